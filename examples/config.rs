@@ -0,0 +1,38 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+#[enum_impl(pub new, pub into_parts, pub get)]
+pub struct Config {
+    #[enum_impl(pub set)]
+    name: String,
+    #[enum_impl(pub get_mut, pub with)]
+    retries: u32,
+    #[enum_impl(skip, pub get = "is_verbose")]
+    verbose: bool,
+}
+
+#[derive(EnumImpl)]
+#[enum_impl(pub new, pub get)]
+pub struct Meters(f64);
+
+fn main() {
+    let mut config = Config::new("server".to_owned(), 3, false);
+    assert_eq!(config.name(), "server");
+    assert_eq!(*config.retries(), 3);
+    assert!(!config.is_verbose());
+
+    config.set_name("client".to_owned());
+    assert_eq!(config.name(), "client");
+
+    *config.retries_mut() += 1;
+    assert_eq!(*config.retries(), 4);
+
+    let config = config.with_retries(10);
+    let (name, retries, verbose) = config.into_parts();
+    assert_eq!(name, "client");
+    assert_eq!(retries, 10);
+    assert!(!verbose);
+
+    let distance = Meters::new(1.5);
+    assert_eq!(*distance.field_0(), 1.5);
+}
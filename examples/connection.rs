@@ -0,0 +1,33 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, Default, PartialEq, EnumImpl)]
+#[enum_impl(pub set, pub replace, pub take, pub get_or_insert, pub is)]
+pub enum Connection {
+    #[default]
+    Disconnected,
+    Connecting {
+        attempt: u32,
+    },
+    Connected {
+        session: String,
+    },
+}
+
+fn main() {
+    let mut connection = Connection::default();
+    connection.set_connecting(1);
+    assert!(connection.is_connecting());
+
+    let previous = connection.replace_with_connected("abc".to_owned());
+    assert_eq!(previous, Connection::Connecting { attempt: 1 });
+
+    assert_eq!(connection.take_connecting(), None);
+    assert!(connection.is_connected());
+
+    assert_eq!(connection.take_connected(), Some("abc".to_owned()));
+    assert!(connection.is_disconnected());
+
+    *connection.get_or_insert_connecting_with(|| 0) += 1;
+    *connection.get_or_insert_connecting_with(|| 0) += 1;
+    assert_eq!(connection, Connection::Connecting { attempt: 2 });
+}
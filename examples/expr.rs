@@ -0,0 +1,37 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    start: usize,
+    end: usize,
+}
+
+#[derive(EnumImpl)]
+#[enum_impl(pub common(span), pub kind, pub as_ref, naming(as_ref = "get_{}"))]
+pub enum Expr {
+    Literal { value: i64, span: Span },
+    Variable { name: String, span: Span },
+    Negate { operand: Box<Expr>, span: Span },
+}
+
+fn main() {
+    let mut expr = Expr::Negate {
+        operand: Box::new(Expr::Literal {
+            value: 1,
+            span: Span { start: 1, end: 2 },
+        }),
+        span: Span { start: 0, end: 2 },
+    };
+    assert_eq!(expr.kind(), ExprKind::Negate);
+    assert_eq!(*expr.span(), Span { start: 0, end: 2 });
+
+    expr.span_mut().start = 1;
+    assert_eq!(expr.into_span(), Span { start: 1, end: 2 });
+
+    let variable = Expr::Variable {
+        name: "x".to_owned(),
+        span: Span { start: 4, end: 5 },
+    };
+    assert_eq!(variable.span().end, 5);
+    assert_eq!(variable.get_variable().map(|(name, _)| name.as_str()), Some("x"));
+}
@@ -0,0 +1,38 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, EnumImpl)]
+#[enum_impl(impl into, impl as_ref)]
+pub enum Message {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(u64),
+    #[enum_impl(impl into(primary), impl as_ref(primary))]
+    Pong(u64),
+    Close,
+}
+
+fn first<'a, T: TryFrom<&'a Message>>(messages: &'a [Message]) -> Option<T> {
+    messages.iter().find_map(|message| T::try_from(message).ok())
+}
+
+fn main() {
+    let messages = [
+        Message::Ping(1),
+        Message::Text("hello".to_owned()),
+        Message::Binary(vec![1, 2, 3]),
+        Message::Pong(2),
+        Message::Close,
+    ];
+
+    assert_eq!(first::<&String>(&messages).unwrap(), "hello");
+    assert_eq!(first::<&Vec<u8>>(&messages).unwrap(), &[1, 2, 3]);
+    assert_eq!(first::<&u64>(&messages), Some(&2));
+
+    let error = String::try_from(Message::Close).unwrap_err();
+    assert_eq!(error.expected, "Message::Text");
+    assert_eq!(error.found, "Message::Close");
+    assert_eq!(error.to_string(), "expected `Message::Text`, found `Message::Close`");
+
+    assert_eq!(u64::try_from(Message::Pong(3)), Ok(3));
+    assert!(u64::try_from(Message::Ping(3)).is_err());
+}
@@ -1,20 +1,27 @@
 use enum_impl::EnumImpl;
 
 #[derive(EnumImpl)]
+#[enum_impl(pub kind, pub view(derive(Debug, Clone, Copy)), pub view_mut)]
 pub enum Shape {
-    #[enum_impl(pub is)]
+    #[enum_impl(pub is(attrs(inline)), impl try_from)]
     Circle(f64),
-    #[enum_impl(pub as_ref, pub as_ref_mut, impl from)]
+    #[enum_impl(pub as_ref(doc = "Returns the width and height of the rectangle."), pub as_ref_mut, impl from)]
     Rectangle(f64, f64),
-    #[enum_impl(pub as_ref, pub as_ref_mut, pub into)]
+    #[enum_impl(pub as_ref, pub as_ref_mut, pub into, pub try_into, pub map, pub map_or)]
     Square(f64),
-    #[enum_impl(pub from = "create_cuboid", pub is, pub into)]
+    /// Rectangular box, whose faces are rectangles.
+    #[enum_impl(pub from = "create_cuboid", pub is(variant_doc), pub payload(derive(Debug, Clone, PartialEq)), pub fields)]
     Cuboid { width: f64, height: f64, depth: f64 },
 }
 
 fn main() {
     let circle = Shape::Circle(5.0);
     assert!(circle.is_circle());
+    assert_eq!(circle.kind(), ShapeKind::Circle);
+    assert_eq!(ShapeKind::from(&circle), ShapeKind::Circle);
+    assert_eq!(ShapeKind::ALL.len(), 4);
+    let Ok(radius) = f64::try_from(circle) else { panic!() };
+    assert_eq!(radius, 5.0);
 
     let rectangle = Shape::from((3.0, 4.0));
     assert_eq!(rectangle.as_rectangle().unwrap(), (&3.0, &4.0));
@@ -26,11 +33,44 @@ fn main() {
     assert_eq!(*square.as_square().unwrap(), 15.0);
     assert_eq!(square.into_square().unwrap(), 15.0);
 
-    let cuboid = Shape::create_cuboid(2.0, 3.0, 4.0);
+    let square = Shape::Square(5.0).map_square(|side| side * 2.0);
+    assert_eq!(square.map_square_or(0.0, |side| side * side), 100.0);
+
+    let square = Shape::Square(10.0);
+    let Ok(side) = square.try_into_square() else { panic!() };
+    assert_eq!(side, 10.0);
+
+    let Err(shape) = Shape::Circle(1.0).try_into_square() else {
+        panic!()
+    };
+    assert!(shape.is_circle());
+
+    let cuboid = Shape::create_cuboid(2.0, 3.0, 1.0);
     assert!(cuboid.is_cuboid());
+    assert_eq!(cuboid.cuboid_height(), Some(&3.0));
+    let ShapeCuboidRef { width, height, depth } = cuboid.as_cuboid().unwrap();
+    assert_eq!((*width, *height, *depth), (2.0, 3.0, 1.0));
 
-    let (width, height, depth) = cuboid.into_cuboid().unwrap();
-    assert_eq!(width, 2.0);
-    assert_eq!(height, 3.0);
-    assert_eq!(depth, 4.0);
+    let mut cuboid = cuboid;
+    if let ShapeMut::Cuboid { depth, .. } = cuboid.as_view_mut() {
+        *depth = 4.0;
+    }
+    let ShapeRef::Cuboid { width, .. } = cuboid.as_view() else {
+        panic!()
+    };
+    assert_eq!(*width, 2.0);
+    assert_eq!(format!("{:?}", Shape::Square(1.5).as_view()), "Square(1.5)");
+
+    let payload = cuboid.into_cuboid().unwrap();
+    assert_eq!(
+        payload.clone(),
+        ShapeCuboid {
+            width: 2.0,
+            height: 3.0,
+            depth: 4.0
+        }
+    );
+
+    let cuboid = Shape::from(payload);
+    assert!(cuboid.is_cuboid());
 }
@@ -1,22 +1,31 @@
 use enum_impl::EnumImpl;
 
 #[derive(EnumImpl)]
+#[enum_impl(
+    pub const is,
+    pub variant_name(case = "snake_case"),
+    pub group(name = "is_input", variants(KeyPress, Paste, Click), view, derive(Debug, Clone, Copy)),
+)]
 pub enum WebEvent {
-    #[enum_impl(pub is)]
     PageLoad,
-    #[enum_impl(pub is)]
     PageUnload,
-    #[enum_impl(pub as_ref, as_ref_mut, impl from)]
+    #[enum_impl(skip, pub as_ref, as_ref_mut, impl from, pub(crate) unwrap_ref, pub(crate) unwrap_mut)]
     KeyPress(char),
-    #[enum_impl(pub as_ref, as_ref_mut, pub into)]
+    #[enum_impl(skip(is), pub as_ref, as_ref_mut, pub into, pub unwrap, pub expect)]
     Paste(String),
-    #[enum_impl(pub from = "click_from_coordinates", pub is, pub as_ref)]
-    Click { x: i64, y: i64 },
+    #[enum_impl(pub from = "click_from_coordinates", pub as_ref, name = "mouse_click")]
+    Click {
+        x: i64,
+        y: i64,
+    },
 }
 
 fn main() {
+    const { assert!(!WebEvent::PageLoad.is_page_unload()) };
+
     let page_load = WebEvent::PageLoad;
     assert!(page_load.is_page_load());
+    assert_eq!(page_load.variant_name(), "page_load");
 
     let page_unload = WebEvent::PageUnload;
     assert!(page_unload.is_page_unload());
@@ -27,13 +36,31 @@ fn main() {
     *key_press.as_key_press_mut().unwrap() = 'd';
     assert_eq!(*key_press.as_key_press().unwrap(), 'd');
 
+    *key_press.unwrap_key_press_mut() = 'e';
+    assert_eq!(*key_press.unwrap_key_press_ref(), 'e');
+
     let paste = WebEvent::Paste("hello world".to_owned());
     assert_eq!(paste.as_paste().unwrap(), "hello world");
     assert_eq!(paste.into_paste().unwrap(), "hello world".to_owned());
 
+    let paste = WebEvent::Paste("hello world".to_owned());
+    assert_eq!(paste.unwrap_paste(), "hello world".to_owned());
+
+    let paste = WebEvent::Paste("hello world".to_owned());
+    assert_eq!(paste.expect_paste("not a paste"), "hello world".to_owned());
+
     let click = WebEvent::click_from_coordinates(-10, 10);
     assert!(click.is_click());
-    let WebEvent::Click { x, y } = click else { panic!() };
-    assert_eq!(x, -10);
-    assert_eq!(y, 10);
+    assert_eq!(click.variant_name(), "mouse_click");
+    assert_eq!(WebEvent::VARIANT_NAMES.len(), 5);
+    let WebEvent::Click { x, y } = &click else { panic!() };
+    assert_eq!(*x, -10);
+    assert_eq!(*y, 10);
+
+    assert!(!page_load.is_input());
+    assert_eq!(format!("{:?}", page_load.as_input()), "None");
+    let WebEventInput::Click { x, y } = click.as_input().unwrap() else {
+        panic!()
+    };
+    assert_eq!((*x, *y), (-10, 10));
 }
@@ -1,102 +1,1449 @@
-use proc_macro2::{Ident, Span};
+use convert_case::{Case, Casing};
+use proc_macro2::{Group, Ident, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::ext::IdentExt;
 use syn::meta::ParseNestedMeta;
-use syn::Token;
+use syn::parse::{Parse, Parser};
+use syn::spanned::Spanned;
+use syn::{Token, Visibility};
 
-#[derive(Debug, Clone, Default)]
+/// Attributes placed on the enum itself.
+#[derive(Clone, Default)]
+pub struct EnumAttributes {
+    /// Methods inherited by every variant, unless overridden or skipped.
+    pub defaults: EnumImplAttributes,
+    pub kind: Option<KindAttribute>,
+    pub variant_name: Option<VariantNameAttribute>,
+    pub common: Option<CommonAttribute>,
+    pub naming: Option<NamingAttribute>,
+    pub groups: Vec<GroupAttribute>,
+    pub view: Option<ViewAttribute>,
+    pub view_mut: Option<ViewAttribute>,
+    /// Makes every method that can be `const` so.
+    pub constness: Option<Token![const]>,
+    /// Whether the methods get their default documentation, `true` unless `doc = false` is set.
+    pub doc: Option<syn::LitBool>,
+}
+
+/// Attributes placed on the struct itself.
+#[derive(Clone, Default)]
+pub struct StructAttributes {
+    /// Methods inherited by every field, unless overridden or skipped.
+    pub defaults: FieldImplAttributes,
+    pub new: Option<ClassicAttribute>,
+    pub into_parts: Option<ClassicAttribute>,
+    /// Makes every method that can be `const` so.
+    pub constness: Option<Token![const]>,
+}
+
+#[derive(Clone, Default)]
+pub struct FieldImplAttributes {
+    pub get: Option<ClassicAttribute>,
+    pub get_mut: Option<ClassicAttribute>,
+    pub set: Option<ClassicAttribute>,
+    pub with: Option<ClassicAttribute>,
+    pub skip: Skip,
+}
+
+#[derive(Clone, Default)]
 pub struct EnumImplAttributes {
     pub as_ref_mut: Option<ClassicAttribute>,
-    pub as_ref: Option<ClassicAttribute>,
+    pub get_or_insert: Option<ClassicAttribute>,
+    pub as_ref: Option<ImplOrClassicAttribute>,
     pub from: Option<ImplOrClassicAttribute>,
-    pub into: Option<ClassicAttribute>,
+    pub into: Option<ImplOrClassicAttribute>,
     pub is: Option<ClassicAttribute>,
+    pub try_from: Option<ImplAttribute>,
+    pub try_into: Option<ClassicAttribute>,
+    pub unwrap: Option<ClassicAttribute>,
+    pub unwrap_ref: Option<ClassicAttribute>,
+    pub unwrap_mut: Option<ClassicAttribute>,
+    pub expect: Option<ClassicAttribute>,
+    pub fields: Option<ClassicAttribute>,
+    pub fields_mut: Option<ClassicAttribute>,
+    pub map: Option<ClassicAttribute>,
+    pub and_then: Option<ClassicAttribute>,
+    pub map_or: Option<ClassicAttribute>,
+    pub set: Option<ClassicAttribute>,
+    pub replace: Option<ClassicAttribute>,
+    pub take: Option<ClassicAttribute>,
+    pub payload: Option<PayloadAttribute>,
+    pub skip: Skip,
+    /// Name of the variant returned by `variant_name`, overriding the enum level case.
+    pub name: Option<VariantName>,
+}
+
+#[derive(Clone)]
+pub struct VariantName {
+    pub value: String,
+    pub span: Span,
+}
+
+/// Enum or struct level defaults a variant or a field opts out of.
+#[derive(Clone, Default)]
+pub enum Skip {
+    #[default]
+    None,
+    All,
+    Some(Vec<Ident>),
+}
+
+impl Skip {
+    /// Parses `skip` or `skip(name, ...)`, where every name must be one of `names`.
+    fn new(meta: &ParseNestedMeta, names: &[&str]) -> syn::Result<Self> {
+        if !meta.input.peek(syn::token::Paren) {
+            return Ok(Self::All);
+        }
+
+        let mut skipped = Vec::new();
+
+        meta.parse_nested_meta(|inner| {
+            let name = inner.path.require_ident()?;
+            if !names.contains(&name.to_string().as_str()) {
+                return Err(syn::Error::new_spanned(name, "invalid enum_impl attribute"));
+            }
+            skipped.push(name.clone());
+            Ok(())
+        })?;
+
+        Ok(Self::Some(skipped))
+    }
+
+    /// Also skips `names`.
+    fn add(&mut self, names: &[&str]) {
+        let names = names.iter().map(|name| Ident::new(name, Span::call_site()));
+
+        match self {
+            Self::None => *self = Self::Some(names.collect()),
+            Self::All => {},
+            Self::Some(skipped) => skipped.extend(names),
+        }
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Some(names) => names.iter().any(|ident| ident == name),
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ClassicAttribute {
-    pub public: bool,
+    pub vis: Visibility,
+    pub constness: Option<Token![const]>,
     pub rename: Option<Ident>,
+    /// Span of the attribute, used to report errors on the generated method.
+    pub span: Span,
+    pub attrs: ForwardedAttributes,
+    pub doc: Documentation,
+    pub named: Option<Named>,
+}
+
+/// `named [= "Name"]` option, returning the fields of a variant with several named fields as a generated struct
+/// instead of a tuple.
+#[derive(Clone)]
+pub struct Named {
+    pub span: Span,
+    /// Name of the struct, `{Enum}{Variant}` by default, followed by `Ref` or `Mut` for references.
+    pub name: Option<Ident>,
+    /// Whether variants with a single named field get a struct too, set by `payload`.
+    pub single_field: bool,
+    /// Derives of the struct, replacing the default ones, given with the `derive(...)` option.
+    pub derives: Option<Vec<TokenStream>>,
+}
+
+/// Attributes forwarded onto the generated methods, declared with the `attrs(...)` option.
+#[derive(Clone, Default)]
+pub struct ForwardedAttributes(Vec<syn::Meta>);
+
+/// Documentation of a generated method, replaced with the `doc = "..."` option.
+#[derive(Clone, Default)]
+pub struct Documentation {
+    /// Replaces the default documentation.
+    pub custom: Option<String>,
+    /// Whether the default documentation is omitted, set by the enum level `doc = false`.
+    pub suppressed: bool,
+    /// Span of the `variant_doc` option, appending the documentation of the variant.
+    variant: Option<Span>,
+    /// Documentation of the variant, once resolved.
+    appended: Vec<syn::Attribute>,
 }
 
-#[derive(Debug, Clone)]
+/// Generates a fieldless enum with the same variants, and a method returning it.
+#[derive(Clone)]
+pub struct KindAttribute {
+    pub vis: Visibility,
+    pub constness: Option<Token![const]>,
+    pub rename: Option<Ident>,
+    pub span: Span,
+    /// Name of the generated enum, `{Enum}Kind` by default.
+    pub name: Option<Ident>,
+    /// Derives of the generated enum, replacing the default ones.
+    pub derives: Option<Vec<TokenStream>>,
+    pub attrs: ForwardedAttributes,
+    pub doc: Documentation,
+}
+
+/// Generates an enum with the same variants whose fields are references to the fields of the enum, and a method
+/// returning it.
+#[derive(Clone)]
+pub struct ViewAttribute {
+    pub vis: Visibility,
+    pub constness: Option<Token![const]>,
+    pub rename: Option<Ident>,
+    pub span: Span,
+    /// Name of the generated enum, `{Enum}Ref` or `{Enum}Mut` by default.
+    pub name: Option<Ident>,
+    /// Derives of the generated enum, replacing the default ones.
+    pub derives: Option<Vec<TokenStream>>,
+    pub attrs: ForwardedAttributes,
+    pub doc: Documentation,
+}
+
+/// Generates a method returning the name of the variant, and a constant listing every name.
+#[derive(Clone)]
+pub struct VariantNameAttribute {
+    pub vis: Visibility,
+    pub constness: Option<Token![const]>,
+    pub rename: Option<Ident>,
+    pub span: Span,
+    /// Case the variant identifiers are converted to. They are kept as is by default.
+    pub case: Option<Case>,
+    /// Whether to implement `FromStr`, parsing the names back.
+    pub from_str: bool,
+    pub attrs: ForwardedAttributes,
+    pub doc: Documentation,
+}
+
+/// Generates accessors to fields shared by every variant.
+#[derive(Clone)]
+pub struct CommonAttribute {
+    pub vis: Visibility,
+    pub span: Span,
+    pub attrs: ForwardedAttributes,
+    pub doc: Documentation,
+    /// Declared shared fields. If `None`, every named field shared by all variants with the same type is used.
+    pub fields: Option<Vec<Ident>>,
+}
+
+/// Generates a predicate matching several variants, and optionally a view over them.
+#[derive(Clone)]
+pub struct GroupAttribute {
+    pub vis: Visibility,
+    pub constness: Option<Token![const]>,
+    pub span: Span,
+    /// Name of the predicate, e.g. `is_terminal`.
+    pub name: Ident,
+    pub variants: Vec<Ident>,
+    /// Name of the method returning the view, if any.
+    pub view: Option<Ident>,
+    /// Derives of the view enum, replacing the default ones.
+    pub derives: Option<Vec<TokenStream>>,
+    pub attrs: ForwardedAttributes,
+    pub doc: Documentation,
+}
+
+/// Naming conventions of the methods generated for every variant.
+#[derive(Clone, Default)]
+pub struct NamingAttribute {
+    /// Method name templates by attribute name, where `{}` stands for the variant name.
+    pub templates: Vec<(String, String)>,
+    /// Case the variant identifiers are converted to in method names, `snake_case` by default.
+    pub case: Option<Case>,
+}
+
+/// Generates structs holding the fields of a variant with named fields, returned by `into` and `as_ref`, along with
+/// a `From` implementation for the enum.
+#[derive(Clone)]
+pub struct PayloadAttribute {
+    pub vis: Visibility,
+    pub span: Span,
+    /// Derives of the struct holding the fields, replacing the default ones.
+    pub derives: Option<Vec<TokenStream>>,
+}
+
+/// Trait implementation generated for a variant, e.g. `impl from`.
+#[derive(Clone)]
+pub struct ImplAttribute {
+    /// Whether this implementation wins over the ones of other variants with the same associated data type.
+    pub primary: bool,
+    pub span: Span,
+}
+
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum ImplOrClassicAttribute {
     Classic(ClassicAttribute),
-    Impl,
+    Impl(ImplAttribute),
 }
 
 impl ClassicAttribute {
+    fn from_decoded(decoded: ParametrizedAttribute) -> syn::Result<Self> {
+        let mut res = Self {
+            vis: decoded.visibility()?,
+            constness: decoded.constness,
+            span: decoded.span(),
+            rename: decoded.param,
+            attrs: ForwardedAttributes::default(),
+            doc: Documentation::default(),
+            named: None,
+        };
+
+        let mut derives = None;
+
+        if let Some(options) = decoded.options {
+            let parser = syn::meta::parser(|meta| {
+                if meta.path.is_ident("named") {
+                    let name = if meta.input.peek(Token![=]) {
+                        Some(meta.value()?.parse::<syn::LitStr>()?.parse()?)
+                    } else {
+                        None
+                    };
+                    res.named = Some(Named {
+                        span: meta.path.span(),
+                        name,
+                        single_field: false,
+                        derives: None,
+                    });
+                    Ok(())
+                } else if meta.path.is_ident("derive") {
+                    derives = Some((meta.path.span(), parse_derives(&meta)?));
+                    Ok(())
+                } else if meta.path.is_ident("attrs") {
+                    res.attrs.extend(&meta)
+                } else if meta.path.is_ident("doc") {
+                    res.doc.set(&meta)
+                } else if meta.path.is_ident("variant_doc") {
+                    res.doc.variant = Some(meta.path.span());
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "invalid option, expected `attrs(...)`, `doc = \"...\"`, `variant_doc`, `named` or \
+                         `derive(...)`",
+                    ))
+                }
+            });
+
+            parser.parse2(options.stream())?;
+        }
+
+        if let Some((span, derives)) = derives {
+            let Some(named) = &mut res.named else {
+                return Err(syn::Error::new(span, "`derive(...)` requires the `named` option"));
+            };
+            named.derives = Some(derives);
+        }
+
+        Ok(res)
+    }
+
+    /// Name of the generated method: the rename if any, `default` otherwise.
+    pub fn function_name(&self, default: Ident) -> Ident {
+        self.rename.clone().unwrap_or(default)
+    }
+
+    /// Decodes an attribute generating one method per field, which therefore cannot be renamed.
+    fn from_decoded_per_field(decoded: ParametrizedAttribute) -> syn::Result<Self> {
+        if let Some(rename) = decoded.param {
+            return Err(syn::Error::new_spanned(
+                rename,
+                "attributes generating one method per field cannot be renamed",
+            ));
+        }
+
+        Self::from_decoded(decoded)
+    }
+
+    /// Rejects the `variant_doc` and `named` options outside of enums.
+    fn reject_enum_options(&self) -> syn::Result<()> {
+        if let Some(span) = self.doc.variant {
+            return Err(syn::Error::new(span, "`variant_doc` is only available on enums"));
+        }
+        self.reject_named()
+    }
+
+    /// Rejects the `named` option on attributes not returning the associated data.
+    fn reject_named(&self) -> syn::Result<()> {
+        match &self.named {
+            Some(named) => Err(syn::Error::new(
+                named.span,
+                "`named` is only available on `as_ref`, `as_ref_mut` and `into`",
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ForwardedAttributes {
+    /// Parses `attrs(...)`, e.g. `attrs(inline, deprecated(note = "..."))`.
+    fn extend(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        self.0.extend(content.parse_terminated(syn::Meta::parse, Token![,])?);
+        Ok(())
+    }
+
+    /// Forwarded attributes, with `#[must_use]` unless it is already forwarded.
+    pub fn with_must_use(&self) -> TokenStream {
+        if self.0.iter().any(|meta| meta.path().is_ident("must_use")) {
+            self.to_token_stream()
+        } else {
+            quote! { #[must_use] #self }
+        }
+    }
+}
+
+impl ToTokens for ForwardedAttributes {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let attrs = &self.0;
+        tokens.extend(quote! { #(#[#attrs])* });
+    }
+}
+
+impl Documentation {
+    /// Parses `doc = "..."`.
+    fn set(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        let lit = meta.value()?.parse::<syn::LitStr>()?;
+        self.custom = Some(lit.value());
+        Ok(())
+    }
+
+    /// Applies the enum level `doc = false`, and picks the documentation of the variant if `variant_doc` is set.
+    pub fn resolve(&mut self, suppressed: bool, variant_attrs: &[syn::Attribute]) {
+        self.suppressed = suppressed;
+        if self.variant.is_some() {
+            self.appended = variant_attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("doc"))
+                .cloned()
+                .collect();
+        }
+    }
+
+    /// Default documentation, omitted if it is for `self`. Used by methods derived from another attribute.
+    pub fn default_only(&self) -> Self {
+        Self {
+            suppressed: self.suppressed,
+            ..Self::default()
+        }
+    }
+
+    /// Doc attributes of the method: the custom documentation if any, `default` otherwise unless suppressed, followed
+    /// by the documentation of the variant.
+    pub fn render(&self, default: String) -> TokenStream {
+        let main = match &self.custom {
+            Some(custom) => Some(custom.clone()),
+            None => (!self.suppressed).then_some(default),
+        };
+
+        let appended = &self.appended;
+        let separator = (main.is_some() && !appended.is_empty()).then(|| quote! { #[doc = ""] });
+        let main = main.map(|main| quote! { #[doc = #main] });
+
+        quote! {
+            #main
+            #separator
+            #(#appended)*
+        }
+    }
+}
+
+impl KindAttribute {
+    fn from_decoded(decoded: ParametrizedAttribute) -> syn::Result<Self> {
+        let vis = decoded.visibility()?;
+
+        let mut res = Self {
+            vis,
+            constness: decoded.constness,
+            span: decoded.span(),
+            rename: decoded.param,
+            name: None,
+            derives: None,
+            attrs: ForwardedAttributes::default(),
+            doc: Documentation::default(),
+        };
+
+        if let Some(options) = decoded.options {
+            let parser = syn::meta::parser(|meta| {
+                if meta.path.is_ident("name") {
+                    let lit = meta.value()?.parse::<syn::LitStr>()?;
+                    res.name = Some(lit.parse()?);
+                } else if meta.path.is_ident("derive") {
+                    res.derives = Some(parse_derives(&meta)?);
+                } else if meta.path.is_ident("attrs") {
+                    res.attrs.extend(&meta)?;
+                } else if meta.path.is_ident("doc") {
+                    res.doc.set(&meta)?;
+                } else {
+                    return Err(meta.error("invalid kind option"));
+                }
+                Ok(())
+            });
+
+            parser.parse2(options.stream())?;
+        }
+
+        Ok(res)
+    }
+}
+
+impl ViewAttribute {
+    fn from_decoded(decoded: ParametrizedAttribute) -> syn::Result<Self> {
+        let vis = decoded.visibility()?;
+
+        let mut res = Self {
+            vis,
+            constness: decoded.constness,
+            span: decoded.span(),
+            rename: decoded.param,
+            name: None,
+            derives: None,
+            attrs: ForwardedAttributes::default(),
+            doc: Documentation::default(),
+        };
+
+        if let Some(options) = decoded.options {
+            let parser = syn::meta::parser(|meta| {
+                if meta.path.is_ident("name") {
+                    let lit = meta.value()?.parse::<syn::LitStr>()?;
+                    res.name = Some(lit.parse()?);
+                } else if meta.path.is_ident("derive") {
+                    res.derives = Some(parse_derives(&meta)?);
+                } else if meta.path.is_ident("attrs") {
+                    res.attrs.extend(&meta)?;
+                } else if meta.path.is_ident("doc") {
+                    res.doc.set(&meta)?;
+                } else {
+                    return Err(meta.error("invalid view option"));
+                }
+                Ok(())
+            });
+
+            parser.parse2(options.stream())?;
+        }
+
+        Ok(res)
+    }
+}
+
+impl VariantNameAttribute {
+    fn from_decoded(decoded: ParametrizedAttribute) -> syn::Result<Self> {
+        let vis = decoded.visibility()?;
+
+        let mut res = Self {
+            vis,
+            constness: decoded.constness,
+            span: decoded.span(),
+            rename: decoded.param,
+            case: None,
+            from_str: false,
+            attrs: ForwardedAttributes::default(),
+            doc: Documentation::default(),
+        };
+
+        if let Some(options) = decoded.options {
+            let parser = syn::meta::parser(|meta| {
+                if meta.path.is_ident("case") {
+                    res.case = Some(parse_case(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident("from_str") {
+                    res.from_str = true;
+                } else if meta.path.is_ident("attrs") {
+                    res.attrs.extend(&meta)?;
+                } else if meta.path.is_ident("doc") {
+                    res.doc.set(&meta)?;
+                } else {
+                    return Err(meta.error("invalid variant_name option"));
+                }
+                Ok(())
+            });
+
+            parser.parse2(options.stream())?;
+        }
+
+        Ok(res)
+    }
+}
+
+impl CommonAttribute {
+    fn from_decoded(decoded: ParametrizedAttribute) -> syn::Result<Self> {
+        let vis = decoded.visibility()?;
+        decoded.reject_const()?;
+
+        if let Some(rename) = decoded.param {
+            return Err(syn::Error::new_spanned(
+                rename,
+                "attributes generating one method per field cannot be renamed",
+            ));
+        }
+
+        let mut attrs = ForwardedAttributes::default();
+        let mut doc = Documentation::default();
+
+        let fields = match decoded.options {
+            Some(options) => {
+                let mut fields = Vec::new();
+                let parser = syn::meta::parser(|meta| {
+                    if meta.path.is_ident("attrs") && meta.input.peek(syn::token::Paren) {
+                        return attrs.extend(&meta);
+                    }
+                    if meta.path.is_ident("doc") && meta.input.peek(Token![=]) {
+                        return doc.set(&meta);
+                    }
+
+                    fields.push(meta.path.require_ident()?.clone());
+                    Ok(())
+                });
+                parser.parse2(options.stream())?;
+                (!fields.is_empty()).then_some(fields)
+            },
+            None => None,
+        };
+
+        Ok(Self {
+            vis,
+            span: decoded.name.span(),
+            attrs,
+            doc,
+            fields,
+        })
+    }
+}
+
+impl GroupAttribute {
+    fn from_decoded(decoded: ParametrizedAttribute) -> syn::Result<Self> {
+        let vis = decoded.visibility()?;
+
+        if let Some(rename) = decoded.param {
+            return Err(syn::Error::new_spanned(
+                rename,
+                "this attribute cannot be renamed, use the `name` option",
+            ));
+        }
+
+        let mut name = None;
+        let mut variants = None;
+        let mut view = None;
+        let mut derives = None;
+        let mut attrs = ForwardedAttributes::default();
+        let mut doc = Documentation::default();
+
+        if let Some(options) = &decoded.options {
+            let parser = syn::meta::parser(|meta| {
+                if meta.path.is_ident("name") {
+                    let lit = meta.value()?.parse::<syn::LitStr>()?;
+                    name = Some(method_ident(&lit.value(), lit.span())?);
+                } else if meta.path.is_ident("variants") {
+                    let mut idents = Vec::new();
+                    meta.parse_nested_meta(|inner| {
+                        idents.push(inner.path.require_ident()?.clone());
+                        Ok(())
+                    })?;
+                    variants = Some(idents);
+                } else if meta.path.is_ident("view") {
+                    view = Some(if meta.input.peek(Token![=]) {
+                        let lit = meta.value()?.parse::<syn::LitStr>()?;
+                        Some(method_ident(&lit.value(), lit.span())?)
+                    } else {
+                        None
+                    });
+                } else if meta.path.is_ident("derive") {
+                    derives = Some(parse_derives(&meta)?);
+                } else if meta.path.is_ident("attrs") {
+                    attrs.extend(&meta)?;
+                } else if meta.path.is_ident("doc") {
+                    doc.set(&meta)?;
+                } else {
+                    return Err(meta.error("invalid group option"));
+                }
+                Ok(())
+            });
+
+            parser.parse2(options.stream())?;
+        }
+
+        let Some(name) = name else {
+            return Err(syn::Error::new_spanned(
+                decoded.name,
+                "missing group name, e.g. `group(name = \"is_terminal\", variants(...))`",
+            ));
+        };
+        let Some(variants) = variants.filter(|variants| !variants.is_empty()) else {
+            return Err(syn::Error::new_spanned(
+                decoded.name,
+                "missing group variants, e.g. `group(name = \"is_terminal\", variants(...))`",
+            ));
+        };
+
+        if let (Some(_), None) = (&derives, &view) {
+            return Err(syn::Error::new_spanned(
+                decoded.name,
+                "`derive(...)` requires the `view` option",
+            ));
+        }
+
+        // `view` alone names the method after the predicate, replacing its `is_` prefix with `as_`.
+        let view = view.map(|rename| {
+            rename.unwrap_or_else(|| {
+                let name = name.unraw().to_string();
+                format_ident!("as_{}", name.strip_prefix("is_").unwrap_or(&name))
+            })
+        });
+
+        Ok(Self {
+            vis,
+            constness: decoded.constness,
+            span: decoded.name.span(),
+            name,
+            variants,
+            view,
+            derives,
+            attrs,
+            doc,
+        })
+    }
+}
+
+impl NamingAttribute {
+    fn from_decoded(decoded: ParametrizedAttribute) -> syn::Result<Self> {
+        if !matches!(decoded.keyword, Keyword::None) {
+            return Err(syn::Error::new_spanned(
+                decoded.name,
+                "this attribute does not take a keyword",
+            ));
+        }
+        if let Some(rename) = decoded.param {
+            return Err(syn::Error::new_spanned(rename, "this attribute cannot be renamed"));
+        }
+        decoded.reject_const()?;
+
+        let mut res = Self::default();
+
+        if let Some(options) = decoded.options {
+            let parser = syn::meta::parser(|meta| {
+                if meta.path.is_ident("case") {
+                    let lit = meta.value()?.parse()?;
+                    match parse_case(&lit)? {
+                        Case::Kebab | Case::Cobol => {
+                            return Err(syn::Error::new_spanned(lit, "method names cannot be in kebab case"));
+                        },
+                        case => res.case = Some(case),
+                    }
+                } else if let Some((name, _)) = DEFAULT_TEMPLATES.iter().find(|(name, _)| meta.path.is_ident(name)) {
+                    let lit = meta.value()?.parse::<syn::LitStr>()?;
+                    let template = lit.value();
+
+                    if template.matches("{}").count() != 1
+                        || syn::parse_str::<Ident>(&template.replace("{}", "variant")).is_err()
+                    {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "invalid naming template, expected a method name containing `{}` exactly once",
+                        ));
+                    }
+
+                    res.templates.retain(|(other, _)| other != name);
+                    res.templates.push((name.to_string(), template));
+                } else {
+                    return Err(meta.error("invalid naming option"));
+                }
+                Ok(())
+            });
+
+            parser.parse2(options.stream())?;
+        }
+
+        Ok(res)
+    }
+
+    /// Name of the method generated by `attribute` for `variant_ident`, following the templates.
+    pub fn method_name(&self, attribute: &str, variant_ident: &Ident) -> syn::Result<Ident> {
+        let template = self
+            .templates
+            .iter()
+            .find(|(name, _)| name == attribute)
+            .map(|(_, template)| template.as_str())
+            .or_else(|| {
+                DEFAULT_TEMPLATES
+                    .iter()
+                    .find(|(name, _)| *name == attribute)
+                    .map(|(_, template)| *template)
+            })
+            .unwrap();
+
+        let name = template.replacen("{}", &self.variant_name(variant_ident), 1);
+        method_ident(&name, variant_ident.span())
+    }
+
+    /// Variant identifier converted to the case of the method names.
+    pub fn variant_name(&self, variant_ident: &Ident) -> String {
+        variant_ident
+            .unraw()
+            .to_string()
+            .to_case(self.case.unwrap_or(Case::Snake))
+    }
+}
+
+impl PayloadAttribute {
+    fn from_decoded(decoded: ParametrizedAttribute) -> syn::Result<Self> {
+        let vis = decoded.visibility()?;
+
+        if let Some(rename) = decoded.param {
+            return Err(syn::Error::new_spanned(
+                rename,
+                "this attribute cannot be renamed, rename `into` and `as_ref` instead",
+            ));
+        }
+
+        let mut derives = None;
+
+        if let Some(options) = decoded.options {
+            let parser = syn::meta::parser(|meta| {
+                if meta.path.is_ident("derive") {
+                    derives = Some(parse_derives(&meta)?);
+                } else {
+                    return Err(meta.error("invalid payload option"));
+                }
+                Ok(())
+            });
+
+            parser.parse2(options.stream())?;
+        }
+
+        Ok(Self {
+            vis,
+            span: decoded.name.span(),
+            derives,
+        })
+    }
+}
+
+impl ImplAttribute {
+    /// Decodes an attribute that only exists in its `impl` form.
     fn from_decoded(decoded: ParametrizedAttribute) -> syn::Result<Self> {
         match decoded.keyword {
-            Keyword::None => Ok(Self {
-                public: false,
-                rename: decoded.param,
-            }),
-            Keyword::Pub => Ok(Self {
-                public: true,
-                rename: decoded.param,
-            }),
-            Keyword::Impl => Err(syn::Error::new_spanned(
+            Keyword::Impl => Self::from_impl(decoded),
+            _ => Err(syn::Error::new_spanned(
                 decoded.name,
-                "invalid keyword `impl` for this attribute",
+                "this attribute requires the `impl` keyword",
             )),
         }
     }
+
+    fn from_impl(decoded: ParametrizedAttribute) -> syn::Result<Self> {
+        if let Some(rename) = decoded.param {
+            return Err(syn::Error::new_spanned(rename, "impl attributes cannot be renamed"));
+        }
+
+        let mut primary = false;
+
+        if let Some(options) = decoded.options {
+            let parser = syn::meta::parser(|meta| {
+                if meta.path.is_ident("primary") {
+                    primary = true;
+                } else {
+                    return Err(meta.error("invalid impl option"));
+                }
+                Ok(())
+            });
+
+            parser.parse2(options.stream())?;
+        }
+
+        Ok(Self {
+            primary,
+            span: decoded.name.span(),
+        })
+    }
 }
 
 impl ImplOrClassicAttribute {
     fn from_decoded(decoded: ParametrizedAttribute) -> syn::Result<Self> {
         match decoded.keyword {
-            Keyword::Impl => {
-                if let Some(rename) = decoded.param {
-                    Err(syn::Error::new_spanned(rename, "impl attributes cannot be renamed"))
+            Keyword::Impl => Ok(Self::Impl(ImplAttribute::from_impl(decoded)?)),
+            _ => Ok(Self::Classic(ClassicAttribute::from_decoded(decoded)?)),
+        }
+    }
+
+    /// Parameters of the generated method, unless this is the `impl` form.
+    pub fn classic(&self) -> Option<&ClassicAttribute> {
+        match self {
+            Self::Classic(params) => Some(params),
+            Self::Impl(_) => None,
+        }
+    }
+
+    /// Mutable parameters of the generated method, unless this is the `impl` form.
+    fn classic_mut(&mut self) -> Option<&mut ClassicAttribute> {
+        match self {
+            Self::Classic(params) => Some(params),
+            Self::Impl(_) => None,
+        }
+    }
+
+    /// Span of the attribute, used to report errors.
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Classic(params) => params.span,
+            Self::Impl(params) => params.span,
+        }
+    }
+}
+
+impl EnumAttributes {
+    pub fn new(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+
+        parse_attributes(attrs, |attr, meta| {
+            if meta.path.is_ident("doc") {
+                let lit = meta.value()?.parse::<syn::LitBool>()?;
+
+                if res.doc.replace(lit).is_some() {
+                    return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
+                }
+
+                return Ok(());
+            }
+
+            let decoded = ParametrizedAttribute::new(&meta)?;
+
+            if decoded.name == "kind" {
+                if res.kind.replace(KindAttribute::from_decoded(decoded)?).is_some() {
+                    return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
+                }
+
+                return Ok(());
+            }
+
+            if decoded.name == "variant_name" {
+                if res
+                    .variant_name
+                    .replace(VariantNameAttribute::from_decoded(decoded)?)
+                    .is_some()
+                {
+                    return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
+                }
+
+                return Ok(());
+            }
+
+            if decoded.name == "const" {
+                return decoded.switch(attr, &mut res.constness);
+            }
+
+            if decoded.name == "naming" {
+                if res.naming.replace(NamingAttribute::from_decoded(decoded)?).is_some() {
+                    return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
+                }
+
+                return Ok(());
+            }
+
+            if decoded.name == "view" || decoded.name == "view_mut" {
+                let view = if decoded.name == "view" {
+                    &mut res.view
                 } else {
-                    Ok(Self::Impl)
+                    &mut res.view_mut
+                };
+
+                if view.replace(ViewAttribute::from_decoded(decoded)?).is_some() {
+                    return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
                 }
-            },
-            _ => Ok(Self::Classic(ClassicAttribute::from_decoded(decoded)?)),
+
+                return Ok(());
+            }
+
+            if decoded.name == "group" {
+                res.groups.push(GroupAttribute::from_decoded(decoded)?);
+                return Ok(());
+            }
+
+            if decoded.name == "common" {
+                if res.common.replace(CommonAttribute::from_decoded(decoded)?).is_some() {
+                    return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
+                }
+
+                return Ok(());
+            }
+
+            if decoded.param.is_some() {
+                return Err(syn::Error::new_spanned(
+                    decoded.name,
+                    "enum level attributes cannot be renamed",
+                ));
+            }
+
+            res.defaults.add(attr, decoded)
+        })?;
+
+        res.defaults.reject_named()?;
+
+        if let Some(constness) = res.constness {
+            // Variant and field methods are made `const` once inherited, see `make_const`.
+            let values = (res.kind.iter_mut().map(|params| &mut params.constness))
+                .chain(res.variant_name.iter_mut().map(|params| &mut params.constness))
+                .chain(res.view.iter_mut().map(|params| &mut params.constness))
+                .chain(res.view_mut.iter_mut().map(|params| &mut params.constness))
+                .chain(res.groups.iter_mut().map(|params| &mut params.constness));
+
+            for value in values {
+                value.get_or_insert(constness);
+            }
+        }
+
+        if res.suppresses_doc() {
+            // Variant methods are documented once inherited, see `document`.
+            let docs = (res.kind.iter_mut().map(|params| &mut params.doc))
+                .chain(res.variant_name.iter_mut().map(|params| &mut params.doc))
+                .chain(res.view.iter_mut().map(|params| &mut params.doc))
+                .chain(res.view_mut.iter_mut().map(|params| &mut params.doc))
+                .chain(res.groups.iter_mut().map(|params| &mut params.doc))
+                .chain(res.common.iter_mut().map(|params| &mut params.doc));
+
+            for doc in docs {
+                doc.suppressed = true;
+            }
+        }
+
+        Ok(res)
+    }
+
+    /// Whether `doc = false` is set, omitting the default documentation of the methods.
+    pub fn suppresses_doc(&self) -> bool {
+        self.doc.as_ref().is_some_and(|lit| !lit.value)
+    }
+}
+
+impl StructAttributes {
+    pub fn new(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+
+        parse_attributes(attrs, |attr, meta| {
+            let decoded = ParametrizedAttribute::new(&meta)?;
+
+            if decoded.name == "const" {
+                return decoded.switch(attr, &mut res.constness);
+            }
+
+            if match decoded.name.to_string().as_str() {
+                "new" => res.new.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+                "into_parts" => res
+                    .into_parts
+                    .replace(ClassicAttribute::from_decoded(decoded)?)
+                    .is_some(),
+                _ => {
+                    if decoded.param.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            decoded.name,
+                            "struct level attributes cannot be renamed",
+                        ));
+                    }
+
+                    return res.defaults.add(attr, decoded);
+                },
+            } {
+                return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
+            }
+
+            Ok(())
+        })?;
+
+        if let Some(constness) = res.constness {
+            make_const_one(res.new.as_mut(), constness);
+            make_const_one(res.into_parts.as_mut(), constness);
+        }
+
+        for params in [&res.new, &res.into_parts].into_iter().flatten() {
+            params.reject_enum_options()?;
+        }
+        res.defaults.reject_enum_options()?;
+
+        Ok(res)
+    }
+}
+
+impl FieldImplAttributes {
+    pub fn new(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+
+        parse_attributes(attrs, |attr, meta| {
+            if meta.path.is_ident("skip") {
+                return res.add_skip(attr, &meta);
+            }
+
+            let decoded = ParametrizedAttribute::new(&meta)?;
+
+            res.add(attr, decoded)
+        })?;
+
+        res.reject_enum_options()?;
+
+        Ok(res)
+    }
+
+    /// Fills every method not set on the field with the struct level default, unless it is skipped. `span` is the one
+    /// of the field, where the errors about inherited methods are reported.
+    pub fn inherit(&mut self, defaults: &Self, span: Span) {
+        inherit_one(&self.skip, span, "get", &mut self.get, &defaults.get);
+        inherit_one(&self.skip, span, "get_mut", &mut self.get_mut, &defaults.get_mut);
+        inherit_one(&self.skip, span, "set", &mut self.set, &defaults.set);
+        inherit_one(&self.skip, span, "with", &mut self.with, &defaults.with);
+    }
+
+    /// Makes every method that can be `const` so.
+    pub fn make_const(&mut self, constness: Token![const]) {
+        make_const_one(self.get.as_mut(), constness);
+        make_const_one(self.get_mut.as_mut(), constness);
+    }
+
+    fn reject_enum_options(&self) -> syn::Result<()> {
+        [&self.get, &self.get_mut, &self.set, &self.with]
+            .into_iter()
+            .flatten()
+            .try_for_each(ClassicAttribute::reject_enum_options)
+    }
+
+    fn add_skip(&mut self, attr: &syn::Attribute, meta: &ParseNestedMeta) -> syn::Result<()> {
+        let skip = Skip::new(meta, FIELD_METHOD_NAMES)?;
+
+        if !matches!(std::mem::replace(&mut self.skip, skip), Skip::None) {
+            return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
+        }
+
+        Ok(())
+    }
+
+    fn add(&mut self, attr: &syn::Attribute, decoded: ParametrizedAttribute) -> syn::Result<()> {
+        if !CONST_FIELD_METHOD_NAMES.iter().any(|name| decoded.name == name) {
+            decoded.reject_const()?;
         }
+
+        if match decoded.name.to_string().as_str() {
+            "get" => self.get.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "get_mut" => self.get_mut.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "set" => self.set.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "with" => self.with.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            _ => return Err(syn::Error::new_spanned(decoded.name, "invalid enum_impl attribute")),
+        } {
+            return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
+        }
+
+        Ok(())
     }
 }
 
 impl EnumImplAttributes {
-    pub fn new(attrs: &Vec<syn::Attribute>) -> syn::Result<Self> {
+    pub fn new(attrs: &[syn::Attribute]) -> syn::Result<Self> {
         let mut res = Self::default();
 
-        for attr in attrs {
-            if !attr.path().is_ident("enum_impl") {
-                continue;
+        parse_attributes(attrs, |attr, meta| {
+            if meta.path.is_ident("skip") {
+                return res.add_skip(attr, &meta);
             }
 
-            if let syn::Meta::List(meta) = &attr.meta {
-                if meta.tokens.is_empty() {
-                    continue;
+            if meta.path.is_ident("name") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                let name = VariantName {
+                    value: lit.value(),
+                    span: lit.span(),
+                };
+
+                if res.name.replace(name).is_some() {
+                    return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
                 }
+
+                return Ok(());
             }
 
-            attr.parse_nested_meta(|meta| {
-                let decoded = ParametrizedAttribute::new(&meta)?;
+            let decoded = ParametrizedAttribute::new(&meta)?;
 
-                res.add(attr, decoded)?;
+            res.add(attr, decoded)
+        })?;
 
-                Ok(())
-            })?;
-        }
+        res.reject_named()?;
 
         Ok(res)
     }
 
+    /// Fills every method not set on the variant with the enum level default, unless it is skipped. `span` is the one
+    /// of the variant, where the errors about inherited methods are reported.
+    pub fn inherit(&mut self, defaults: &Self, span: Span) {
+        inherit_one(
+            &self.skip,
+            span,
+            "as_ref_mut",
+            &mut self.as_ref_mut,
+            &defaults.as_ref_mut,
+        );
+        inherit_one(
+            &self.skip,
+            span,
+            "get_or_insert",
+            &mut self.get_or_insert,
+            &defaults.get_or_insert,
+        );
+        inherit_one(&self.skip, span, "as_ref", &mut self.as_ref, &defaults.as_ref);
+        inherit_one(&self.skip, span, "from", &mut self.from, &defaults.from);
+        inherit_one(&self.skip, span, "into", &mut self.into, &defaults.into);
+        inherit_one(&self.skip, span, "is", &mut self.is, &defaults.is);
+        inherit_one(&self.skip, span, "try_from", &mut self.try_from, &defaults.try_from);
+        inherit_one(&self.skip, span, "try_into", &mut self.try_into, &defaults.try_into);
+        inherit_one(&self.skip, span, "unwrap", &mut self.unwrap, &defaults.unwrap);
+        inherit_one(
+            &self.skip,
+            span,
+            "unwrap_ref",
+            &mut self.unwrap_ref,
+            &defaults.unwrap_ref,
+        );
+        inherit_one(
+            &self.skip,
+            span,
+            "unwrap_mut",
+            &mut self.unwrap_mut,
+            &defaults.unwrap_mut,
+        );
+        inherit_one(&self.skip, span, "expect", &mut self.expect, &defaults.expect);
+        inherit_one(&self.skip, span, "fields", &mut self.fields, &defaults.fields);
+        inherit_one(
+            &self.skip,
+            span,
+            "fields_mut",
+            &mut self.fields_mut,
+            &defaults.fields_mut,
+        );
+        inherit_one(&self.skip, span, "map", &mut self.map, &defaults.map);
+        inherit_one(&self.skip, span, "and_then", &mut self.and_then, &defaults.and_then);
+        inherit_one(&self.skip, span, "map_or", &mut self.map_or, &defaults.map_or);
+        inherit_one(&self.skip, span, "set", &mut self.set, &defaults.set);
+        inherit_one(&self.skip, span, "replace", &mut self.replace, &defaults.replace);
+        inherit_one(&self.skip, span, "take", &mut self.take, &defaults.take);
+        inherit_one(&self.skip, span, "payload", &mut self.payload, &defaults.payload);
+    }
+
+    /// Makes every method that can be `const` so.
+    pub fn make_const(&mut self, constness: Token![const]) {
+        make_const_one(self.as_ref_mut.as_mut(), constness);
+        make_const_one(
+            self.as_ref.as_mut().and_then(ImplOrClassicAttribute::classic_mut),
+            constness,
+        );
+        make_const_one(
+            self.from.as_mut().and_then(ImplOrClassicAttribute::classic_mut),
+            constness,
+        );
+        make_const_one(self.is.as_mut(), constness);
+        make_const_one(self.fields.as_mut(), constness);
+        make_const_one(self.fields_mut.as_mut(), constness);
+    }
+
+    /// Resolves the documentation of every method, given the enum level `doc = false` and the attributes of the
+    /// variant.
+    pub fn document(&mut self, suppressed: bool, variant_attrs: &[syn::Attribute]) {
+        let values = [
+            self.as_ref_mut.as_mut(),
+            self.get_or_insert.as_mut(),
+            self.as_ref.as_mut().and_then(ImplOrClassicAttribute::classic_mut),
+            self.from.as_mut().and_then(ImplOrClassicAttribute::classic_mut),
+            self.into.as_mut().and_then(ImplOrClassicAttribute::classic_mut),
+            self.is.as_mut(),
+            self.try_into.as_mut(),
+            self.unwrap.as_mut(),
+            self.unwrap_ref.as_mut(),
+            self.unwrap_mut.as_mut(),
+            self.expect.as_mut(),
+            self.fields.as_mut(),
+            self.fields_mut.as_mut(),
+            self.map.as_mut(),
+            self.and_then.as_mut(),
+            self.map_or.as_mut(),
+            self.set.as_mut(),
+            self.replace.as_mut(),
+            self.take.as_mut(),
+        ];
+
+        for value in values.into_iter().flatten() {
+            value.doc.resolve(suppressed, variant_attrs);
+        }
+    }
+
+    /// Makes `into` and `as_ref` return the structs generated by `payload`, declaring them if needed.
+    pub fn apply_payload(&mut self) -> syn::Result<()> {
+        let Some(payload) = &self.payload else {
+            return Ok(());
+        };
+
+        for (name, value) in [("into", &mut self.into), ("as_ref", &mut self.as_ref)] {
+            let value = value.get_or_insert_with(|| {
+                ImplOrClassicAttribute::Classic(ClassicAttribute {
+                    vis: payload.vis.clone(),
+                    constness: None,
+                    rename: None,
+                    span: payload.span,
+                    attrs: ForwardedAttributes::default(),
+                    doc: Documentation::default(),
+                    named: None,
+                })
+            });
+            let Some(params) = value.classic_mut() else {
+                return Err(syn::Error::new(
+                    payload.span,
+                    format!("`payload` cannot be combined with `impl {}`", name),
+                ));
+            };
+            let named = params.named.get_or_insert(Named {
+                span: payload.span,
+                name: None,
+                single_field: true,
+                derives: None,
+            });
+            named.single_field = true;
+            if name == "into" && named.derives.is_none() {
+                named.derives.clone_from(&payload.derives);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rejects the `named` option on every method but `as_ref`, `as_ref_mut` and `into`.
+    fn reject_named(&self) -> syn::Result<()> {
+        [
+            self.get_or_insert.as_ref(),
+            self.from.as_ref().and_then(ImplOrClassicAttribute::classic),
+            self.is.as_ref(),
+            self.try_into.as_ref(),
+            self.unwrap.as_ref(),
+            self.unwrap_ref.as_ref(),
+            self.unwrap_mut.as_ref(),
+            self.expect.as_ref(),
+            self.fields.as_ref(),
+            self.fields_mut.as_ref(),
+            self.map.as_ref(),
+            self.and_then.as_ref(),
+            self.map_or.as_ref(),
+            self.set.as_ref(),
+            self.replace.as_ref(),
+            self.take.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .try_for_each(ClassicAttribute::reject_named)
+    }
+
+    /// Rejects the methods declared on the variant that its fields do not support, and opts out of inheriting them.
+    pub fn check_fields(&mut self, fields: &syn::Fields) -> syn::Result<()> {
+        if !matches!(fields, syn::Fields::Named(_)) {
+            if let Some(params) = &self.payload {
+                return Err(syn::Error::new(
+                    params.span,
+                    "`payload` requires a variant with named fields",
+                ));
+            }
+            self.skip.add(&["payload"]);
+        }
+
+        if !fields.is_empty() {
+            return Ok(());
+        }
+
+        let values = [
+            ("as_ref_mut", self.as_ref_mut.as_ref().map(|params| params.span)),
+            ("get_or_insert", self.get_or_insert.as_ref().map(|params| params.span)),
+            ("as_ref", self.as_ref.as_ref().map(ImplOrClassicAttribute::span)),
+            ("into", self.into.as_ref().map(ImplOrClassicAttribute::span)),
+        ];
+
+        for (name, span) in values {
+            if let Some(span) = span {
+                let message = if name == "get_or_insert" {
+                    format!("`{}` requires a variant with fields", name)
+                } else {
+                    format!(
+                        "`{}` requires a variant with fields, use `is` to match unit variants",
+                        name
+                    )
+                };
+                return Err(syn::Error::new(span, message));
+            }
+        }
+
+        self.skip.add(DATA_METHOD_NAMES);
+        self.skip.add(UNIT_METHOD_NAMES);
+        Ok(())
+    }
+
+    fn add_skip(&mut self, attr: &syn::Attribute, meta: &ParseNestedMeta) -> syn::Result<()> {
+        let skip = Skip::new(meta, METHOD_NAMES)?;
+
+        if !matches!(std::mem::replace(&mut self.skip, skip), Skip::None) {
+            return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
+        }
+
+        Ok(())
+    }
+
     fn add(&mut self, attr: &syn::Attribute, decoded: ParametrizedAttribute) -> syn::Result<()> {
+        if !CONST_METHOD_NAMES.iter().any(|name| decoded.name == name) {
+            decoded.reject_const()?;
+        }
+
         if match decoded.name.to_string().as_str() {
             "as_ref_mut" => self
                 .as_ref_mut
                 .replace(ClassicAttribute::from_decoded(decoded)?)
                 .is_some(),
-            "as_ref" => self.as_ref.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "get_or_insert" => self
+                .get_or_insert
+                .replace(ClassicAttribute::from_decoded(decoded)?)
+                .is_some(),
+            "as_ref" => self
+                .as_ref
+                .replace(ImplOrClassicAttribute::from_decoded(decoded)?)
+                .is_some(),
             "from" => self
                 .from
                 .replace(ImplOrClassicAttribute::from_decoded(decoded)?)
                 .is_some(),
-            "into" => self.into.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "into" => self
+                .into
+                .replace(ImplOrClassicAttribute::from_decoded(decoded)?)
+                .is_some(),
             "is" => self.is.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "try_from" => self.try_from.replace(ImplAttribute::from_decoded(decoded)?).is_some(),
+            "try_into" => self
+                .try_into
+                .replace(ClassicAttribute::from_decoded(decoded)?)
+                .is_some(),
+            "unwrap" => self.unwrap.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "unwrap_ref" => self
+                .unwrap_ref
+                .replace(ClassicAttribute::from_decoded(decoded)?)
+                .is_some(),
+            "unwrap_mut" => self
+                .unwrap_mut
+                .replace(ClassicAttribute::from_decoded(decoded)?)
+                .is_some(),
+            "expect" => self.expect.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "fields" => self
+                .fields
+                .replace(ClassicAttribute::from_decoded_per_field(decoded)?)
+                .is_some(),
+            "fields_mut" => self
+                .fields_mut
+                .replace(ClassicAttribute::from_decoded_per_field(decoded)?)
+                .is_some(),
+            "map" => self.map.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "and_then" => self
+                .and_then
+                .replace(ClassicAttribute::from_decoded(decoded)?)
+                .is_some(),
+            "map_or" => self.map_or.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "set" => self.set.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "replace" => self.replace.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "take" => self.take.replace(ClassicAttribute::from_decoded(decoded)?).is_some(),
+            "payload" => self.payload.replace(PayloadAttribute::from_decoded(decoded)?).is_some(),
             _ => return Err(syn::Error::new_spanned(decoded.name, "invalid enum_impl attribute")),
         } {
             return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
@@ -106,43 +1453,330 @@ impl EnumImplAttributes {
     }
 }
 
-#[derive(Clone, Copy, Default, Debug)]
+/// Sets `value` to `default` if it is not set and not skipped, reporting its errors at `span`.
+fn inherit_one<T: Clone + Respan>(skip: &Skip, span: Span, name: &str, value: &mut Option<T>, default: &Option<T>) {
+    if value.is_none() && !skip.contains(name) {
+        value.clone_from(default);
+        if let Some(value) = value {
+            value.respan(span);
+        }
+    }
+}
+
+/// Attribute whose errors can be reported somewhere else, e.g. on the variant inheriting it.
+trait Respan {
+    fn respan(&mut self, span: Span);
+}
+
+impl Respan for ClassicAttribute {
+    fn respan(&mut self, span: Span) {
+        self.span = span;
+        if let Some(named) = &mut self.named {
+            named.span = span;
+        }
+    }
+}
+
+impl Respan for ImplAttribute {
+    fn respan(&mut self, span: Span) {
+        self.span = span;
+    }
+}
+
+impl Respan for ImplOrClassicAttribute {
+    fn respan(&mut self, span: Span) {
+        match self {
+            Self::Classic(params) => params.respan(span),
+            Self::Impl(params) => params.respan(span),
+        }
+    }
+}
+
+impl Respan for PayloadAttribute {
+    fn respan(&mut self, span: Span) {
+        self.span = span;
+    }
+}
+
+/// Makes the method generated by `value`, if any, `const`.
+fn make_const_one(value: Option<&mut ClassicAttribute>, constness: Token![const]) {
+    if let Some(value) = value {
+        value.constness.get_or_insert(constness);
+    }
+}
+
+/// Names of the methods that can be generated for a variant.
+const METHOD_NAMES: &[&str] = &[
+    "as_ref_mut",
+    "get_or_insert",
+    "as_ref",
+    "from",
+    "into",
+    "is",
+    "try_from",
+    "try_into",
+    "unwrap",
+    "unwrap_ref",
+    "unwrap_mut",
+    "expect",
+    "fields",
+    "fields_mut",
+    "map",
+    "and_then",
+    "map_or",
+    "set",
+    "replace",
+    "take",
+    "payload",
+];
+
+/// Names of the variant methods requiring associated data, which unit variants do not inherit.
+const DATA_METHOD_NAMES: &[&str] = &["as_ref_mut", "get_or_insert", "as_ref", "into"];
+
+/// Names of the variant methods that only produce `()` for unit variants, which unit variants do not inherit either,
+/// e.g. so that several of them do not all implement `From<()>`.
+const UNIT_METHOD_NAMES: &[&str] = &[
+    "from",
+    "try_from",
+    "try_into",
+    "unwrap",
+    "unwrap_ref",
+    "unwrap_mut",
+    "expect",
+    "map",
+    "and_then",
+    "map_or",
+    "take",
+];
+
+/// Attributes whose method names follow a naming template, with their default template.
+const DEFAULT_TEMPLATES: &[(&str, &str)] = &[
+    ("as_ref_mut", "as_{}_mut"),
+    ("get_or_insert", "get_or_insert_{}_with"),
+    ("as_ref", "as_{}"),
+    ("from", "from_{}"),
+    ("into", "into_{}"),
+    ("is", "is_{}"),
+    ("try_into", "try_into_{}"),
+    ("unwrap", "unwrap_{}"),
+    ("unwrap_ref", "unwrap_{}_ref"),
+    ("unwrap_mut", "unwrap_{}_mut"),
+    ("expect", "expect_{}"),
+    ("map", "map_{}"),
+    ("and_then", "and_then_{}"),
+    ("map_or", "map_{}_or"),
+    ("set", "set_{}"),
+    ("replace", "replace_with_{}"),
+    ("take", "take_{}"),
+];
+
+/// Names of the methods that can be generated for a struct field.
+const FIELD_METHOD_NAMES: &[&str] = &["get", "get_mut", "set", "with"];
+
+/// Names of the variant methods that can be `const`. The others consume or drop data, or take closures.
+const CONST_METHOD_NAMES: &[&str] = &["as_ref_mut", "as_ref", "from", "is", "fields", "fields_mut"];
+
+/// Names of the field methods that can be `const`. The others drop the previous value of the field.
+const CONST_FIELD_METHOD_NAMES: &[&str] = &["get", "get_mut"];
+
+/// Parses the paths of a `derive(...)` option.
+fn parse_derives(meta: &ParseNestedMeta) -> syn::Result<Vec<TokenStream>> {
+    let mut derives = Vec::new();
+    meta.parse_nested_meta(|inner| {
+        derives.push(inner.path.to_token_stream());
+        Ok(())
+    })?;
+    Ok(derives)
+}
+
+/// Parses a case name, spelled like serde's `rename_all`.
+fn parse_case(lit: &syn::LitStr) -> syn::Result<Case> {
+    match lit.value().as_str() {
+        "lowercase" => Ok(Case::Flat),
+        "UPPERCASE" => Ok(Case::UpperFlat),
+        "PascalCase" => Ok(Case::Pascal),
+        "camelCase" => Ok(Case::Camel),
+        "snake_case" => Ok(Case::Snake),
+        "SCREAMING_SNAKE_CASE" => Ok(Case::ScreamingSnake),
+        "kebab-case" => Ok(Case::Kebab),
+        "SCREAMING-KEBAB-CASE" => Ok(Case::Cobol),
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "invalid case, expected one of `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`, \
+             `SCREAMING_SNAKE_CASE`, `kebab-case` or `SCREAMING-KEBAB-CASE`",
+        )),
+    }
+}
+
+/// Parses a method name, which may be a raw identifier. Keywords are turned into raw identifiers.
+pub fn method_ident(value: &str, span: Span) -> syn::Result<Ident> {
+    let name = value.strip_prefix("r#").unwrap_or(value);
+
+    let mut ident = syn::parse_str::<Ident>(name)
+        .or_else(|_| syn::parse_str::<Ident>(&format!("r#{}", name)))
+        .map_err(|_| syn::Error::new(span, format!("`{}` is not a valid method name", value)))?;
+
+    ident.set_span(span);
+    Ok(ident)
+}
+
+/// Calls `f` on every item of every non-empty `enum_impl` attribute.
+fn parse_attributes(
+    attrs: &[syn::Attribute],
+    mut f: impl FnMut(&syn::Attribute, ParseNestedMeta) -> syn::Result<()>,
+) -> syn::Result<()> {
+    for attr in attrs {
+        if !attr.path().is_ident("enum_impl") {
+            continue;
+        }
+
+        if let syn::Meta::List(meta) = &attr.meta {
+            if meta.tokens.is_empty() {
+                continue;
+            }
+        }
+
+        attr.parse_nested_meta(|meta| f(attr, meta))?;
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Default)]
 enum Keyword {
     #[default]
     None,
-    Pub,
+    Vis(Visibility),
     Impl,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 struct ParametrizedAttribute {
     keyword: Keyword,
     name: Ident,
     param: Option<Ident>,
+    /// Parenthesized options following the attribute, e.g. `kind(name = "ShapeKind")`.
+    options: Option<Group>,
+    /// `const` keyword following the visibility, e.g. `pub const is`.
+    constness: Option<Token![const]>,
 }
 
 impl<'a> ParametrizedAttribute {
     pub fn new(meta: &'a ParseNestedMeta) -> syn::Result<ParametrizedAttribute> {
-        let ident = meta.path.get_ident().unwrap().clone();
+        let ident = meta.path.require_ident()?.clone();
 
         let (keyword, name) = if meta.path.is_ident("pub") {
-            (Keyword::Pub, meta.input.parse()?)
+            let restriction = if meta.input.peek(syn::token::Paren) {
+                Some(meta.input.parse::<Group>()?)
+            } else {
+                None
+            };
+
+            let pub_token = &meta.path;
+            let vis = syn::parse2(quote! { #pub_token #restriction }).map_err(|_| {
+                syn::Error::new_spanned(
+                    &restriction,
+                    "invalid visibility, expected `pub(crate)`, `pub(self)`, `pub(super)` or `pub(in path)`",
+                )
+            })?;
+
+            (Keyword::Vis(vis), None)
         } else if meta.path.is_ident("impl") {
-            (Keyword::Impl, meta.input.parse()?)
+            (Keyword::Impl, Some(meta.input.parse()?))
+        } else if meta.path.is_ident("const") {
+            (Keyword::None, None)
+        } else {
+            (Keyword::None, Some(ident.clone()))
+        };
+
+        let constness = if meta.path.is_ident("const") {
+            Some(Token![const](ident.span()))
+        } else if matches!(keyword, Keyword::Vis(_)) && meta.input.peek(Token![const]) {
+            Some(meta.input.parse()?)
         } else {
-            (Keyword::None, ident)
+            None
+        };
+
+        let name = match name {
+            Some(name) => name,
+            // A bare `const` is the switch making every method that can be `const` so.
+            None if meta.path.is_ident("const") && (meta.input.is_empty() || meta.input.peek(Token![,])) => ident,
+            None => meta.input.parse()?,
         };
 
         let lookahead = meta.input.lookahead1();
 
         let param = if lookahead.peek(Token![=]) {
             meta.input.parse::<Token![=]>().unwrap();
-            let lit = meta.input.parse::<syn::LitStr>()?;
-            Some(Ident::new(&lit.value(), Span::call_site()))
+
+            let lookahead = meta.input.lookahead1();
+            if lookahead.peek(syn::LitStr) {
+                let lit = meta.input.parse::<syn::LitStr>()?;
+                Some(method_ident(&lit.value(), lit.span())?)
+            } else if lookahead.peek(Ident::peek_any) {
+                let ident = meta.input.call(Ident::parse_any)?;
+                Some(method_ident(&ident.to_string(), ident.span())?)
+            } else {
+                return Err(lookahead.error());
+            }
+        } else {
+            None
+        };
+
+        let options = if meta.input.peek(syn::token::Paren) {
+            Some(meta.input.parse()?)
         } else {
             None
         };
 
-        Ok(Self { keyword, name, param })
+        Ok(Self {
+            keyword,
+            name,
+            param,
+            options,
+            constness,
+        })
+    }
+
+    /// Span of the rename if any, of the attribute name otherwise.
+    fn span(&self) -> Span {
+        self.param.as_ref().map_or(self.name.span(), Ident::span)
+    }
+
+    fn visibility(&self) -> syn::Result<Visibility> {
+        match &self.keyword {
+            Keyword::None => Ok(Visibility::Inherited),
+            Keyword::Vis(vis) => Ok(vis.clone()),
+            Keyword::Impl => Err(syn::Error::new_spanned(
+                &self.name,
+                "invalid keyword `impl` for this attribute",
+            )),
+        }
+    }
+
+    /// Decodes a bare switch such as `const`, which takes no keyword, rename nor options.
+    fn switch(self, attr: &syn::Attribute, value: &mut Option<Token![const]>) -> syn::Result<()> {
+        if !matches!(self.keyword, Keyword::None) || self.param.is_some() || self.options.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "invalid `const` attribute, expected a bare `const`",
+            ));
+        }
+
+        if value.replace(self.constness.unwrap()).is_some() {
+            return Err(syn::Error::new_spanned(attr, "duplicated attribute"));
+        }
+
+        Ok(())
+    }
+
+    fn reject_const(&self) -> syn::Result<()> {
+        match &self.constness {
+            Some(constness) => Err(syn::Error::new_spanned(
+                constness,
+                "this attribute cannot generate a `const` method",
+            )),
+            None => Ok(()),
+        }
     }
 }
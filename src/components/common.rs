@@ -0,0 +1,110 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{DataEnum, Type};
+
+use crate::attr::CommonAttribute;
+
+pub fn expand_common_field_ref(
+    enum_ident: &Ident,
+    data_enum: &DataEnum,
+    field_ident: &Ident,
+    function_name: &Ident,
+    ty: &Type,
+    params: &CommonAttribute,
+) -> TokenStream {
+    let documentation = format!(
+        "Returns an immutable reference to the `{}` field, shared by every [`{}`] variant.",
+        field_ident, enum_ident
+    );
+
+    expand_common_field(
+        data_enum,
+        field_ident,
+        params,
+        params.attrs.to_token_stream(),
+        function_name,
+        documentation,
+        quote! { &self },
+        quote! { &#ty },
+    )
+}
+
+pub fn expand_common_field_mut(
+    enum_ident: &Ident,
+    data_enum: &DataEnum,
+    field_ident: &Ident,
+    function_name: &Ident,
+    ty: &Type,
+    params: &CommonAttribute,
+) -> TokenStream {
+    let documentation = format!(
+        "Returns a mutable reference to the `{}` field, shared by every [`{}`] variant.",
+        field_ident, enum_ident
+    );
+
+    expand_common_field(
+        data_enum,
+        field_ident,
+        params,
+        params.attrs.to_token_stream(),
+        function_name,
+        documentation,
+        quote! { &mut self },
+        quote! { &mut #ty },
+    )
+}
+
+pub fn expand_common_field_into(
+    enum_ident: &Ident,
+    data_enum: &DataEnum,
+    field_ident: &Ident,
+    function_name: &Ident,
+    ty: &Type,
+    params: &CommonAttribute,
+) -> TokenStream {
+    let documentation = format!(
+        "Converts into the `{}` field, shared by every [`{}`] variant.",
+        field_ident, enum_ident
+    );
+
+    expand_common_field(
+        data_enum,
+        field_ident,
+        params,
+        params.attrs.with_must_use(),
+        function_name,
+        documentation,
+        quote! { self },
+        quote! { #ty },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn expand_common_field(
+    data_enum: &DataEnum,
+    field_ident: &Ident,
+    params: &CommonAttribute,
+    attrs: TokenStream,
+    function_name: &Ident,
+    documentation: String,
+    receiver: TokenStream,
+    ret: TokenStream,
+) -> TokenStream {
+    let keyword = &params.vis;
+    let documentation = params.doc.render(documentation);
+
+    let arms = data_enum.variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        quote! { Self::#variant_ident { #field_ident: field, .. } => field, }
+    });
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword fn #function_name(#receiver) -> #ret {
+            match self {
+                #(#arms)*
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Member, Type};
+
+use crate::attr::ClassicAttribute;
+
+pub fn expand_field_ref(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    member: &Member,
+    ty: &Type,
+) -> TokenStream {
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+    let constness = &params.constness;
+
+    let documentation = params.doc.render(format!(
+        "Returns an immutable reference to the `{}` field if it is the [`{}::{}`] variant. Otherwise, returns `None`.",
+        quote! { #member },
+        enum_ident,
+        variant_ident
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword #constness fn #function_name(&self) -> Option<&#ty> {
+            match self {
+                Self::#variant_ident { #member: field, .. } => Some(field),
+                _ => None,
+            }
+        }
+    }
+}
+
+pub fn expand_field_mut(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    member: &Member,
+    ty: &Type,
+) -> TokenStream {
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+    let constness = &params.constness;
+
+    let documentation = params.doc.render(format!(
+        "Returns a mutable reference to the `{}` field if it is the [`{}::{}`] variant. Otherwise, returns `None`.",
+        quote! { #member },
+        enum_ident,
+        variant_ident
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword #constness fn #function_name(&mut self) -> Option<&mut #ty> {
+            match self {
+                Self::#variant_ident { #member: field, .. } => Some(field),
+                _ => None,
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Member, Type};
+
+use crate::attr::ClassicAttribute;
+
+pub fn expand_get(function_name: &Ident, member: &Member, params: &ClassicAttribute, ty: &Type) -> TokenStream {
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+    let constness = &params.constness;
+
+    let documentation = params.doc.render(format!(
+        "Returns an immutable reference to the `{}` field.",
+        quote! { #member }
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword #constness fn #function_name(&self) -> &#ty {
+            &self.#member
+        }
+    }
+}
+
+pub fn expand_get_mut(function_name: &Ident, member: &Member, params: &ClassicAttribute, ty: &Type) -> TokenStream {
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+    let constness = &params.constness;
+
+    let documentation = params.doc.render(format!(
+        "Returns a mutable reference to the `{}` field.",
+        quote! { #member }
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword #constness fn #function_name(&mut self) -> &mut #ty {
+            &mut self.#member
+        }
+    }
+}
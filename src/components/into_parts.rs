@@ -0,0 +1,32 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Fields;
+
+use crate::attr::ClassicAttribute;
+use crate::fields::VariantFields;
+
+pub fn expand_into_parts(
+    struct_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+) -> TokenStream {
+    let VariantFields { ty, destruct, ret, .. } = VariantFields::new(fields, quote! {});
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+    let constness = &params.constness;
+
+    let documentation = params
+        .doc
+        .render(format!("Converts the [`{}`] into its fields.", struct_ident));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword #constness fn #function_name(self) -> #ty {
+            let Self #destruct = self;
+            #ret
+        }
+    }
+}
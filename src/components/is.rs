@@ -1,41 +1,32 @@
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::Fields;
+use syn::{Fields, Variant};
 
-use crate::attr::ClassicAttribute;
+use crate::attr::{ClassicAttribute, GroupAttribute};
+use crate::fields::wildcard_pattern;
 
 pub fn expand_is(
     enum_ident: &Ident,
     variant_ident: &Ident,
-    variant_name_snake_case: &str,
+    function_name: &Ident,
     params: &ClassicAttribute,
     fields: &Fields,
 ) -> TokenStream {
-    let function_name = params
-        .rename
-        .clone()
-        .unwrap_or(Ident::new(&format!("is_{variant_name_snake_case}"), Span::call_site()));
-
-    let destruct = match fields {
-        Fields::Named(_) => quote! { { .. } },
-        Fields::Unnamed(_) => quote! { ( .. ) },
-        Fields::Unit => quote! {},
-    };
-
-    let keyword = if params.public {
-        quote! { pub }
-    } else {
-        quote! {}
-    };
-
-    let documentation = format!(
+    let destruct = wildcard_pattern(fields);
+
+    let keyword = &params.vis;
+    let attrs = params.attrs.with_must_use();
+    let constness = &params.constness;
+
+    let documentation = params.doc.render(format!(
         "Returns `true` if it is the [`{}::{}`] variant. Otherwise, returns `false`.",
         enum_ident, variant_ident
-    );
+    ));
 
     quote! {
-        #[doc = #documentation]
-        #keyword fn #function_name(&self) -> bool {
+        #documentation
+        #attrs
+        #keyword #constness fn #function_name(&self) -> bool {
             match self {
                 Self::#variant_ident #destruct => true,
                 _ => false,
@@ -43,3 +34,38 @@ pub fn expand_is(
         }
     }
 }
+
+pub fn expand_is_any(enum_ident: &Ident, params: &GroupAttribute, variants: &[&Variant]) -> TokenStream {
+    let patterns = variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        let destruct = wildcard_pattern(&variant.fields);
+        quote! { Self::#variant_ident #destruct }
+    });
+
+    let function_name = &params.name;
+    let keyword = &params.vis;
+    let attrs = params.attrs.with_must_use();
+    let constness = &params.constness;
+
+    let documentation = params.doc.render(format!(
+        "Returns `true` if it is one of the {} variants. Otherwise, returns `false`.",
+        variant_links(enum_ident, variants)
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword #constness fn #function_name(&self) -> bool {
+            matches!(self, #(#patterns)|*)
+        }
+    }
+}
+
+/// Intra-doc links to `variants`, e.g. ``[`Enum::A`], [`Enum::B`]``.
+pub fn variant_links(enum_ident: &Ident, variants: &[&Variant]) -> String {
+    variants
+        .iter()
+        .map(|variant| format!("[`{}::{}`]", enum_ident, variant.ident))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,82 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{DataEnum, DeriveInput};
+
+use crate::attr::KindAttribute;
+use crate::fields::wildcard_pattern;
+
+pub fn expand_kind(
+    input: &DeriveInput,
+    data_enum: &DataEnum,
+    kind_ident: &Ident,
+    function_name: &Ident,
+    params: &KindAttribute,
+) -> TokenStream {
+    let enum_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let derives = match &params.derives {
+        Some(derives) => quote! { #(#derives),* },
+        None => quote! { Debug, Clone, Copy, PartialEq, Eq, Hash },
+    };
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+    let constness = &params.constness;
+
+    let mut kind_variants = TokenStream::default();
+    let mut arms = TokenStream::default();
+
+    for variant in &data_enum.variants {
+        let variant_ident = &variant.ident;
+        let destruct = wildcard_pattern(&variant.fields);
+
+        let documentation = format!("Kind of the [`{}::{}`] variant.", enum_ident, variant_ident);
+
+        kind_variants.extend(quote! {
+            #[doc = #documentation]
+            #variant_ident,
+        });
+        arms.extend(quote! { Self::#variant_ident #destruct => #kind_ident::#variant_ident, });
+    }
+
+    let all = data_enum.variants.iter().map(|variant| &variant.ident);
+    let len = data_enum.variants.len();
+
+    let kind_documentation = format!("Fieldless counterpart of [`{}`], identifying its variants.", enum_ident);
+    let all_documentation = format!("Every [`{}`], in declaration order.", kind_ident);
+    let function_documentation = params
+        .doc
+        .render(format!("Returns the [`{}`] of the variant.", kind_ident));
+    let from_documentation = format!("Returns the [`{}`] of the variant.", kind_ident);
+
+    quote! {
+        #[doc = #kind_documentation]
+        #[derive(#derives)]
+        #keyword enum #kind_ident {
+            #kind_variants
+        }
+
+        impl #kind_ident {
+            #[doc = #all_documentation]
+            #keyword const ALL: [Self; #len] = [#(Self::#all),*];
+        }
+
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #function_documentation
+            #attrs
+            #keyword #constness fn #function_name(&self) -> #kind_ident {
+                match self {
+                    #arms
+                }
+            }
+        }
+
+        impl #impl_generics From<&#enum_ident #ty_generics> for #kind_ident #where_clause {
+            #[doc = #from_documentation]
+            fn from(value: &#enum_ident #ty_generics) -> Self {
+                value.#function_name()
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Fields, Generics};
+
+use crate::attr::ClassicAttribute;
+use crate::fields::{mentions, VariantFields};
+
+pub fn expand_map(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+) -> TokenStream {
+    let VariantFields { ty, destruct, ret, .. } = VariantFields::new(fields, quote! {});
+    let f = closure_ident();
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+
+    let documentation = params.doc.render(format!(
+        "Transforms the associated data with `f` if it is the [`{}::{}`] variant. Otherwise, returns `self` unchanged.",
+        enum_ident, variant_ident
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword fn #function_name(self, #f: impl FnOnce(#ty) -> #ty) -> Self {
+            match self {
+                Self::#variant_ident #destruct => {
+                    let #ret = #f(#ret);
+                    Self::#variant_ident #destruct
+                },
+                other => other,
+            }
+        }
+    }
+}
+
+pub fn expand_and_then(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+) -> TokenStream {
+    let VariantFields { ty, destruct, ret, .. } = VariantFields::new(fields, quote! {});
+    let f = closure_ident();
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+
+    let documentation = params.doc.render(format!(
+        "Calls `f` with the associated data if it is the [`{}::{}`] variant and returns its result. Otherwise, returns `self` unchanged.",
+        enum_ident, variant_ident
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword fn #function_name(self, #f: impl FnOnce(#ty) -> Self) -> Self {
+            match self {
+                Self::#variant_ident #destruct => #f(#ret),
+                other => other,
+            }
+        }
+    }
+}
+
+pub fn expand_map_or(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+    generics: &Generics,
+) -> TokenStream {
+    let VariantFields { ty, destruct, ret, .. } = VariantFields::new(fields, quote! {});
+    let f = closure_ident();
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+
+    let default = Ident::new("default", Span::mixed_site());
+
+    // The output type parameter must not shadow a type parameter of the enum, nor a type named in the fields.
+    let field_types = fields
+        .iter()
+        .map(|field| field.ty.to_token_stream())
+        .collect::<TokenStream>();
+    let mut output = format_ident!("U");
+    while generics.type_params().any(|param| param.ident == output) || mentions(field_types.clone(), &output) {
+        output = format_ident!("{}_", output);
+    }
+
+    let documentation = params.doc.render(format!(
+        "Returns `f` applied to the associated data if it is the [`{}::{}`] variant. Otherwise, returns `default`.",
+        enum_ident, variant_ident
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword fn #function_name<#output>(self, #default: #output, #f: impl FnOnce(#ty) -> #output) -> #output {
+            match self {
+                Self::#variant_ident #destruct => #f(#ret),
+                _ => #default,
+            }
+        }
+    }
+}
+
+/// Identifier of the closure parameter, which does not collide with the bindings of the fields, e.g. a field named `f`.
+fn closure_ident() -> Ident {
+    Ident::new("f", Span::mixed_site())
+}
@@ -2,13 +2,61 @@ mod as_ref;
 pub use as_ref::expand_as_ref;
 
 mod as_ref_mut;
-pub use as_ref_mut::expand_as_ref_mut;
+pub use as_ref_mut::{expand_as_ref_mut, expand_get_or_insert_with};
+
+mod common;
+pub use common::{expand_common_field_into, expand_common_field_mut, expand_common_field_ref};
+
+mod field_ref;
+pub use field_ref::{expand_field_mut, expand_field_ref};
 
 mod from;
-pub use from::{expand_from_foreign, expand_from_local};
+pub use from::{expand_from_foreign, expand_from_local, expand_from_payload};
+
+mod get;
+pub use get::{expand_get, expand_get_mut};
 
 mod into;
 pub use into::expand_into;
 
+mod into_parts;
+pub use into_parts::expand_into_parts;
+
 mod is;
-pub use is::expand_is;
+pub use is::{expand_is, expand_is_any};
+
+mod kind;
+pub use kind::expand_kind;
+
+mod map;
+pub use map::{expand_and_then, expand_map, expand_map_or};
+
+mod new;
+pub use new::expand_new;
+
+mod payload;
+pub use payload::{expand_payload_struct, Borrow, PayloadStruct};
+
+mod replace;
+pub use replace::{expand_replace_with, expand_set_variant, expand_take};
+
+mod set;
+pub use set::expand_set;
+
+mod try_from;
+pub use try_from::{expand_try_from_error, expand_try_from_foreign, expand_try_from_variant, try_from_error_ident};
+
+mod try_into;
+pub use try_into::expand_try_into;
+
+mod unwrap;
+pub use unwrap::{expand_expect, expand_unwrap, expand_unwrap_mut, expand_unwrap_ref};
+
+mod variant_name;
+pub use variant_name::{expand_variant_name, parse_error_ident};
+
+mod view;
+pub use view::expand_view;
+
+mod with;
+pub use with::expand_with;
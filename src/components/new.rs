@@ -0,0 +1,31 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Fields;
+
+use crate::attr::ClassicAttribute;
+use crate::fields::VariantFields;
+
+pub fn expand_new(
+    struct_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+) -> TokenStream {
+    let VariantFields { destruct, input, .. } = VariantFields::new(fields, quote! {});
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+    let constness = &params.constness;
+
+    let documentation = params
+        .doc
+        .render(format!("Creates a new [`{}`] from its fields.", struct_ident));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword #constness fn #function_name(#input) -> Self {
+            Self #destruct
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{DeriveInput, Lifetime, Variant, Visibility};
+
+use crate::fields::{binding_ident, field_docs, fresh_lifetime, used_generics};
+
+/// How a payload struct holds the fields of its variant.
+#[derive(Clone, Copy)]
+pub enum Borrow {
+    Owned,
+    Shared,
+    Mutable,
+}
+
+/// Struct holding the named fields of a variant, returned instead of a tuple.
+pub struct PayloadStruct {
+    pub definition: TokenStream,
+    /// Type of the struct, with an elided lifetime if the fields are borrowed.
+    pub ty: TokenStream,
+    /// Expression building the struct from the bindings of `VariantFields::destruct`.
+    pub value: TokenStream,
+}
+
+/// Generates a struct named `struct_ident` with the fields of `variant`, owned or borrowed. Unless `derives` is
+/// given, only the struct of shared references derives `Clone` and `Copy`, bounding the type parameters it uses.
+pub fn expand_payload_struct(
+    input: &DeriveInput,
+    variant: &Variant,
+    struct_ident: &Ident,
+    vis: &Visibility,
+    derives: Option<&[TokenStream]>,
+    borrow: Borrow,
+) -> PayloadStruct {
+    let enum_ident = &input.ident;
+    let variant_ident = &variant.ident;
+
+    let (lifetime, elided) = match borrow {
+        Borrow::Owned => (None, None),
+        Borrow::Shared | Borrow::Mutable => (
+            Some(fresh_lifetime(&input.generics)),
+            Some(Lifetime::new("'_", Span::call_site())),
+        ),
+    };
+
+    let generics = used_generics(&input.generics, &[variant], lifetime.as_ref());
+    let (_, _, where_clause) = generics.split_for_impl();
+    let elided_generics = used_generics(&input.generics, &[variant], elided.as_ref());
+    let (_, ty_generics, _) = elided_generics.split_for_impl();
+
+    let (reference, default_derives, documentation) = match borrow {
+        Borrow::Owned => (
+            quote! {},
+            quote! {},
+            format!("Fields of the [`{}::{}`] variant.", enum_ident, variant_ident),
+        ),
+        Borrow::Shared => (
+            quote! { &#lifetime },
+            quote! { Clone, Copy },
+            format!(
+                "References to the fields of the [`{}::{}`] variant.",
+                enum_ident, variant_ident
+            ),
+        ),
+        Borrow::Mutable => (
+            quote! { &#lifetime mut },
+            quote! {},
+            format!(
+                "Mutable references to the fields of the [`{}::{}`] variant.",
+                enum_ident, variant_ident
+            ),
+        ),
+    };
+
+    let derives = match derives {
+        Some(derives) => quote! { #(#derives),* },
+        None => default_derives,
+    };
+
+    let fields = variant.fields.iter().map(|field| {
+        let docs = field_docs(field);
+        let field_ident = &field.ident;
+        let ty = &field.ty;
+        quote! { #(#docs)* #vis #field_ident: #reference #ty }
+    });
+    let idents = variant
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| binding_ident(i, field));
+
+    PayloadStruct {
+        definition: quote! {
+            #[doc = #documentation]
+            #[derive(#derives)]
+            #vis struct #struct_ident #generics #where_clause {
+                #(#fields),*
+            }
+        },
+        ty: quote! { #struct_ident #ty_generics },
+        value: quote! { #struct_ident { #(#idents),* } },
+    }
+}
@@ -0,0 +1,96 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Fields;
+
+use crate::attr::ClassicAttribute;
+use crate::fields::{wildcard_pattern, VariantFields};
+
+pub fn expand_set_variant(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+) -> TokenStream {
+    let VariantFields { destruct, input, .. } = VariantFields::new(fields, quote! {});
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+
+    let documentation = params.doc.render(format!(
+        "Replaces the value with a [`{}::{}`] variant built from the associated data.",
+        enum_ident, variant_ident
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword fn #function_name(&mut self, #input) {
+            *self = Self::#variant_ident #destruct;
+        }
+    }
+}
+
+pub fn expand_replace_with(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+) -> TokenStream {
+    let VariantFields { destruct, input, .. } = VariantFields::new(fields, quote! {});
+
+    let keyword = &params.vis;
+    let attrs = params.attrs.with_must_use();
+
+    let documentation = params.doc.render(format!(
+        "Replaces the value with a [`{}::{}`] variant built from the associated data, returning the previous value.",
+        enum_ident, variant_ident
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword fn #function_name(&mut self, #input) -> Self {
+            ::core::mem::replace(self, Self::#variant_ident #destruct)
+        }
+    }
+}
+
+pub fn expand_take(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+) -> TokenStream {
+    let VariantFields { ty, destruct, ret, .. } = VariantFields::new(fields, quote! {});
+    let pattern = wildcard_pattern(fields);
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+
+    let documentation = params.doc.render(format!(
+        "Takes the associated data if it is the [`{}::{}`] variant, leaving the default value in its place. \
+         Otherwise, returns `None` and leaves the value unchanged.",
+        enum_ident, variant_ident
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword fn #function_name(&mut self) -> Option<#ty>
+        where
+            Self: Default,
+        {
+            if !matches!(self, Self::#variant_ident #pattern) {
+                return None;
+            }
+
+            match ::core::mem::take(self) {
+                Self::#variant_ident #destruct => Some(#ret),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Member, Type};
+
+use crate::attr::ClassicAttribute;
+
+pub fn expand_set(function_name: &Ident, member: &Member, params: &ClassicAttribute, ty: &Type) -> TokenStream {
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+
+    let documentation = params.doc.render(format!("Sets the `{}` field.", quote! { #member }));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword fn #function_name(&mut self, value: #ty) {
+            self.#member = value;
+        }
+    }
+}
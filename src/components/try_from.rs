@@ -0,0 +1,127 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{DeriveInput, Fields, GenericParam, LifetimeParam, Variant};
+
+use super::unwrap::found_variant;
+use crate::fields::{fresh_lifetime, VariantFields};
+
+pub fn expand_try_from_foreign(input: &DeriveInput, variant_ident: &Ident, fields: &Fields) -> TokenStream {
+    let VariantFields { ty, destruct, ret, .. } = VariantFields::new(fields, quote! {});
+
+    let enum_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let documentation = format!(
+        "Converts into the associated data if it is the [`{}::{}`] variant. Otherwise, returns the original value as the error.",
+        enum_ident, variant_ident
+    );
+
+    quote! {
+        impl #impl_generics TryFrom<#enum_ident #ty_generics> for #ty #where_clause {
+            type Error = #enum_ident #ty_generics;
+
+            #[doc = #documentation]
+            fn try_from(value: #enum_ident #ty_generics) -> Result<Self, Self::Error> {
+                match value {
+                    #enum_ident::#variant_ident #destruct => Ok(#ret),
+                    other => Err(other),
+                }
+            }
+        }
+    }
+}
+
+/// Generates the `TryFrom` implementation of `impl into`, or of `impl as_ref` if `borrowed`, failing with the error
+/// type generated by [`expand_try_from_error`].
+pub fn expand_try_from_variant(
+    input: &DeriveInput,
+    variant_ident: &Ident,
+    fields: &Fields,
+    variants: &Punctuated<Variant, Comma>,
+    borrowed: bool,
+) -> TokenStream {
+    let enum_ident = &input.ident;
+    let error_ident = try_from_error_ident(enum_ident);
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut generics = input.generics.clone();
+    let (source, reference) = if borrowed {
+        let lifetime = fresh_lifetime(&input.generics);
+        generics
+            .params
+            .insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+        (quote! { &#lifetime #enum_ident #ty_generics }, quote! { &#lifetime })
+    } else {
+        (quote! { #enum_ident #ty_generics }, quote! {})
+    };
+    let (impl_generics, _, _) = generics.split_for_impl();
+
+    let VariantFields { ty, destruct, ret, .. } = VariantFields::new(fields, reference);
+
+    let borrowing = if borrowed {
+        "references to the associated data"
+    } else {
+        "the associated data"
+    };
+    let documentation = format!(
+        "Converts into {} if it is the [`{}::{}`] variant. Otherwise, returns an error naming the found variant.",
+        borrowing, enum_ident, variant_ident
+    );
+    let expected = format!("{}::{}", enum_ident, variant_ident);
+    let found = found_variant(enum_ident, variants);
+
+    quote! {
+        impl #impl_generics TryFrom<#source> for #ty #where_clause {
+            type Error = #error_ident;
+
+            #[doc = #documentation]
+            fn try_from(value: #source) -> Result<Self, Self::Error> {
+                match value {
+                    #enum_ident::#variant_ident #destruct => Ok(#ret),
+                    other => Err(#error_ident {
+                        expected: #expected,
+                        found: #found,
+                    }),
+                }
+            }
+        }
+    }
+}
+
+/// Generates the error type returned by the implementations of [`expand_try_from_variant`].
+pub fn expand_try_from_error(input: &DeriveInput) -> TokenStream {
+    let enum_ident = &input.ident;
+    let vis = &input.vis;
+    let error_ident = try_from_error_ident(enum_ident);
+
+    let documentation = format!(
+        "Error returned when converting a [`{}`] into the associated data of another variant.",
+        enum_ident
+    );
+
+    quote! {
+        #[doc = #documentation]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis struct #error_ident {
+            /// Path of the expected variant, e.g. `"Enum::A"`.
+            #vis expected: &'static str,
+            /// Path of the variant that was found instead.
+            #vis found: &'static str,
+        }
+
+        impl ::core::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "expected `{}`, found `{}`", self.expected, self.found)
+            }
+        }
+
+        impl ::core::error::Error for #error_ident {}
+    }
+}
+
+/// Name of the error type generated by [`expand_try_from_error`].
+pub fn try_from_error_ident(enum_ident: &Ident) -> Ident {
+    format_ident!("TryFrom{}Error", enum_ident)
+}
@@ -0,0 +1,35 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Fields;
+
+use crate::attr::ClassicAttribute;
+use crate::fields::VariantFields;
+
+pub fn expand_try_into(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+) -> TokenStream {
+    let VariantFields { ty, destruct, ret, .. } = VariantFields::new(fields, quote! {});
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+
+    let documentation = params.doc.render(format!(
+        "Converts into the associated data if it is the [`{}::{}`] variant. Otherwise, returns the original value in `Err`.",
+        enum_ident, variant_ident
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword fn #function_name(self) -> Result<#ty, Self> {
+            match self {
+                Self::#variant_ident #destruct => Ok(#ret),
+                other => Err(other),
+            }
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Fields, Variant};
+
+use crate::attr::ClassicAttribute;
+use crate::fields::{wildcard_pattern, VariantFields};
+
+pub fn expand_unwrap(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+    variants: &Punctuated<Variant, Comma>,
+) -> TokenStream {
+    let documentation = params.doc.render(format!(
+        "Converts into the associated data if it is the [`{}::{}`] variant. Otherwise, panics.",
+        enum_ident, variant_ident
+    ));
+
+    expand_panicking(
+        enum_ident,
+        variant_ident,
+        params,
+        fields,
+        variants,
+        function_name,
+        documentation,
+        quote! { self },
+        quote! {},
+    )
+}
+
+pub fn expand_unwrap_ref(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+    variants: &Punctuated<Variant, Comma>,
+) -> TokenStream {
+    let documentation = params.doc.render(format!(
+        "Returns an immutable reference to the associated data if it is the [`{}::{}`] variant. Otherwise, panics.",
+        enum_ident, variant_ident
+    ));
+
+    expand_panicking(
+        enum_ident,
+        variant_ident,
+        params,
+        fields,
+        variants,
+        function_name,
+        documentation,
+        quote! { &self },
+        quote! { & },
+    )
+}
+
+pub fn expand_unwrap_mut(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+    variants: &Punctuated<Variant, Comma>,
+) -> TokenStream {
+    let documentation = params.doc.render(format!(
+        "Returns a mutable reference to the associated data if it is the [`{}::{}`] variant. Otherwise, panics.",
+        enum_ident, variant_ident
+    ));
+
+    expand_panicking(
+        enum_ident,
+        variant_ident,
+        params,
+        fields,
+        variants,
+        function_name,
+        documentation,
+        quote! { &mut self },
+        quote! { &mut },
+    )
+}
+
+pub fn expand_expect(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    function_name: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+    variants: &Punctuated<Variant, Comma>,
+) -> TokenStream {
+    let VariantFields { ty, destruct, ret, .. } = VariantFields::new(fields, quote! {});
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+
+    let documentation = params.doc.render(format!(
+        "Converts into the associated data if it is the [`{}::{}`] variant. Otherwise, panics with a message including `msg`.",
+        enum_ident, variant_ident
+    ));
+
+    let message = format!("{{}}: expected `{}::{}`, found `{{}}`", enum_ident, variant_ident);
+    let found = found_variant(enum_ident, variants);
+
+    quote! {
+        #documentation
+        #[track_caller]
+        #attrs
+        #keyword fn #function_name(self, msg: &str) -> #ty {
+            match self {
+                Self::#variant_ident #destruct => #ret,
+                other => panic!(#message, msg, #found),
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn expand_panicking(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    params: &ClassicAttribute,
+    fields: &Fields,
+    variants: &Punctuated<Variant, Comma>,
+    function_name: &Ident,
+    documentation: TokenStream,
+    receiver: TokenStream,
+    reference: TokenStream,
+) -> TokenStream {
+    let VariantFields { ty, destruct, ret, .. } = VariantFields::new(fields, reference);
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+
+    let message = format!("expected `{}::{}`, found `{{}}`", enum_ident, variant_ident);
+    let found = found_variant(enum_ident, variants);
+
+    quote! {
+        #documentation
+        #[track_caller]
+        #attrs
+        #keyword fn #function_name(#receiver) -> #ty {
+            match self {
+                Self::#variant_ident #destruct => #ret,
+                other => panic!(#message, #found),
+            }
+        }
+    }
+}
+
+/// Expression evaluating to the path of the variant bound to `other`, e.g. `"Shape::Circle"`.
+pub fn found_variant(enum_ident: &Ident, variants: &Punctuated<Variant, Comma>) -> TokenStream {
+    let arms = variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        let pattern = wildcard_pattern(&variant.fields);
+        let path = format!("{}::{}", enum_ident, variant_ident);
+        quote! { #enum_ident::#variant_ident #pattern => #path, }
+    });
+
+    quote! {
+        match other {
+            #(#arms)*
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DataEnum, DeriveInput, Fields};
+
+use crate::attr::{VariantName, VariantNameAttribute};
+use crate::fields::wildcard_pattern;
+
+pub fn expand_variant_name(
+    input: &DeriveInput,
+    data_enum: &DataEnum,
+    function_name: &Ident,
+    params: &VariantNameAttribute,
+    names: &[VariantName],
+) -> syn::Result<TokenStream> {
+    let enum_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+    let constness = &params.constness;
+
+    let arms = data_enum.variants.iter().zip(names).map(|(variant, name)| {
+        let variant_ident = &variant.ident;
+        let destruct = wildcard_pattern(&variant.fields);
+        let name = &name.value;
+        quote! { Self::#variant_ident #destruct => #name, }
+    });
+
+    let values = names.iter().map(|name| &name.value);
+
+    let function_documentation = params.doc.render("Returns the name of the variant.".to_owned());
+    let constant_documentation = format!("Names of the variants of [`{}`], in declaration order.", enum_ident);
+
+    let mut expanded = quote! {
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #[doc = #constant_documentation]
+            #keyword const VARIANT_NAMES: &'static [&'static str] = &[#(#values),*];
+
+            #function_documentation
+            #attrs
+            #keyword #constness fn #function_name(&self) -> &'static str {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    };
+
+    if params.from_str {
+        expanded.extend(expand_from_str(input, data_enum, names)?);
+    }
+
+    Ok(expanded)
+}
+
+fn expand_from_str(input: &DeriveInput, data_enum: &DataEnum, names: &[VariantName]) -> syn::Result<TokenStream> {
+    let enum_ident = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let error_ident = parse_error_ident(enum_ident);
+
+    let mut seen = HashMap::new();
+    let mut arms = TokenStream::default();
+
+    for (variant, name) in data_enum.variants.iter().zip(names) {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                "`from_str` is only supported on enums whose variants are all unit variants",
+            ));
+        }
+
+        if let Some(previous) = seen.insert(name.value.as_str(), &variant.ident) {
+            return Err(syn::Error::new(
+                name.span,
+                format!("variant name `{}` is already used by `{}`", name.value, previous),
+            ));
+        }
+
+        let variant_ident = &variant.ident;
+        let value = &name.value;
+        arms.extend(quote! { #value => Ok(Self::#variant_ident), });
+    }
+
+    let error_documentation = format!(
+        "Error returned when parsing a [`{}`] from an unknown variant name.",
+        enum_ident
+    );
+    let message = format!("unknown `{}` variant name", enum_ident);
+
+    Ok(quote! {
+        #[doc = #error_documentation]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #vis struct #error_ident;
+
+        impl ::core::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(#message)
+            }
+        }
+
+        impl ::core::error::Error for #error_ident {}
+
+        impl #impl_generics ::core::str::FromStr for #enum_ident #ty_generics #where_clause {
+            type Err = #error_ident;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #arms
+                    _ => Err(#error_ident),
+                }
+            }
+        }
+    })
+}
+
+/// Name of the error type of the `FromStr` implementation.
+pub fn parse_error_ident(enum_ident: &Ident) -> Ident {
+    format_ident!("Parse{}Error", enum_ident)
+}
@@ -0,0 +1,126 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{DataEnum, DeriveInput, Variant};
+
+use super::is::variant_links;
+use crate::attr::ViewAttribute;
+use crate::fields::{field_docs, fresh_lifetime, used_generics, VariantFields};
+
+/// Generates an enum mirroring `variants` whose fields are references to the fields of the enum, and a method
+/// converting to it. The method returns an `Option` if `variants` does not cover every variant.
+pub fn expand_view(
+    input: &DeriveInput,
+    data_enum: &DataEnum,
+    view_ident: &Ident,
+    function_name: &Ident,
+    params: &ViewAttribute,
+    variants: &[&Variant],
+    mutable: bool,
+) -> TokenStream {
+    let enum_ident = &input.ident;
+    let vis = &params.vis;
+    let attrs = &params.attrs;
+    let constness = &params.constness;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let lifetime = fresh_lifetime(&input.generics);
+    let generics = used_generics(&input.generics, variants, Some(&lifetime));
+    let (_, view_ty_generics, view_where_clause) = generics.split_for_impl();
+
+    // Shared references are `Clone` and `Copy` whatever they point to, so the default derives only add the usual
+    // `Clone` and `Copy` bounds on the type parameters of the enum.
+    let (reference, default_derives) = if mutable {
+        (quote! { &#lifetime mut }, quote! {})
+    } else {
+        (quote! { &#lifetime }, quote! { Clone, Copy })
+    };
+    let derives = match &params.derives {
+        Some(derives) => quote! { #(#derives),* },
+        None => default_derives,
+    };
+
+    let mut view_variants = TokenStream::default();
+    let mut arms = TokenStream::default();
+
+    for variant in variants {
+        let variant_ident = &variant.ident;
+        let VariantFields { destruct, .. } = VariantFields::new(&variant.fields, quote! {});
+
+        let fields = variant.fields.iter().map(|field| {
+            let docs = field_docs(field);
+            let ty = &field.ty;
+            match &field.ident {
+                Some(field_ident) => quote! { #(#docs)* #field_ident: #reference #ty },
+                None => quote! { #(#docs)* #reference #ty },
+            }
+        });
+        let fields = match &variant.fields {
+            syn::Fields::Named(_) => quote! { { #(#fields),* } },
+            syn::Fields::Unnamed(_) => quote! { ( #(#fields),* ) },
+            syn::Fields::Unit => quote! {},
+        };
+
+        let documentation = format!("View of the [`{}::{}`] variant.", enum_ident, variant_ident);
+
+        view_variants.extend(quote! {
+            #[doc = #documentation]
+            #variant_ident #fields,
+        });
+        arms.extend(quote! { Self::#variant_ident #destruct => #view_ident::#variant_ident #destruct, });
+    }
+
+    let partial = variants.len() < data_enum.variants.len();
+    let links = variant_links(enum_ident, variants);
+
+    let borrowing = if mutable { "mutably borrowing" } else { "borrowing" };
+
+    let view_documentation = if partial {
+        format!("View of the {} variants, {} their fields.", links, borrowing)
+    } else {
+        format!("View of [`{}`], {} the fields of its variants.", enum_ident, borrowing)
+    };
+
+    let (function_documentation, ret, body) = if partial {
+        (
+            params.doc.render(format!(
+                "Returns a view of the variant if it is one of the {} variants. Otherwise, returns `None`.",
+                links
+            )),
+            quote! { Option<#view_ident #view_ty_generics> },
+            quote! {
+                Some(match self {
+                    #arms
+                    _ => return None,
+                })
+            },
+        )
+    } else {
+        (
+            params
+                .doc
+                .render(format!("Returns a view of the variant, {} its fields.", borrowing)),
+            quote! { #view_ident #view_ty_generics },
+            quote! {
+                match self {
+                    #arms
+                }
+            },
+        )
+    };
+
+    quote! {
+        #[doc = #view_documentation]
+        #[derive(#derives)]
+        #vis enum #view_ident #generics #view_where_clause {
+            #view_variants
+        }
+
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #function_documentation
+            #attrs
+            #vis #constness fn #function_name<#lifetime>(#reference self) -> #ret {
+                #body
+            }
+        }
+    }
+}
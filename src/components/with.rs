@@ -0,0 +1,24 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Member, Type};
+
+use crate::attr::ClassicAttribute;
+
+pub fn expand_with(function_name: &Ident, member: &Member, params: &ClassicAttribute, ty: &Type) -> TokenStream {
+    let keyword = &params.vis;
+    let attrs = &params.attrs;
+
+    let documentation = params.doc.render(format!(
+        "Returns `self` with the `{}` field set to `value`.",
+        quote! { #member }
+    ));
+
+    quote! {
+        #documentation
+        #attrs
+        #keyword fn #function_name(mut self, value: #ty) -> Self {
+            self.#member = value;
+            self
+        }
+    }
+}
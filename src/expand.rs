@@ -1,17 +1,70 @@
 use convert_case::{Case, Casing};
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Data, DeriveInput};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{Data, DataEnum, DataStruct, DeriveInput, Field, Fields, Member, Type, Variant, Visibility};
 
-use crate::attr::{EnumImplAttributes, ImplOrClassicAttribute};
+use crate::attr::{
+    ClassicAttribute,
+    CommonAttribute,
+    EnumAttributes,
+    EnumImplAttributes,
+    FieldImplAttributes,
+    ForwardedAttributes,
+    GroupAttribute,
+    ImplOrClassicAttribute,
+    NamingAttribute,
+    StructAttributes,
+    VariantName,
+    ViewAttribute,
+};
 use crate::components::{
+    expand_and_then,
     expand_as_ref,
     expand_as_ref_mut,
+    expand_common_field_into,
+    expand_common_field_mut,
+    expand_common_field_ref,
+    expand_expect,
+    expand_field_mut,
+    expand_field_ref,
     expand_from_foreign,
     expand_from_local,
+    expand_from_payload,
+    expand_get,
+    expand_get_mut,
+    expand_get_or_insert_with,
     expand_into,
+    expand_into_parts,
     expand_is,
+    expand_is_any,
+    expand_kind,
+    expand_map,
+    expand_map_or,
+    expand_new,
+    expand_payload_struct,
+    expand_replace_with,
+    expand_set,
+    expand_set_variant,
+    expand_take,
+    expand_try_from_error,
+    expand_try_from_foreign,
+    expand_try_from_variant,
+    expand_try_into,
+    expand_unwrap,
+    expand_unwrap_mut,
+    expand_unwrap_ref,
+    expand_variant_name,
+    expand_view,
+    expand_with,
+    parse_error_ident,
+    try_from_error_ident,
+    Borrow,
+    PayloadStruct,
 };
+use crate::fields::{type_key, VariantFields};
+use crate::names::GeneratedNames;
 
 pub fn derive(input: &DeriveInput) -> TokenStream {
     match try_expand(input) {
@@ -26,75 +79,507 @@ pub fn derive(input: &DeriveInput) -> TokenStream {
 }
 
 fn try_expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    match &input.data {
+        Data::Enum(data_enum) => try_expand_enum(input, data_enum),
+        Data::Struct(data_struct) => try_expand_struct(input, data_struct),
+        Data::Union(_) => Err(syn::Error::new_spanned(input, "unions are not supported")),
+    }
+}
+
+fn try_expand_enum(input: &DeriveInput, data_enum: &DataEnum) -> syn::Result<TokenStream> {
     let enum_ident = &input.ident;
     let generics = &input.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let Data::Enum(data_enum) = &input.data else {
-        return Err(syn::Error::new_spanned(input, "only enums are supported"));
-    };
+    let enum_attributes = EnumAttributes::new(&input.attrs)?;
 
     let mut expanded = TokenStream::default();
     let mut foreign_impls = TokenStream::default();
+    let mut variant_names = Vec::new();
+    let mut variant_attributes = Vec::new();
+    let mut from_impls = Vec::new();
+    let mut into_impls = Vec::new();
+    let mut try_from_impls = Vec::new();
+    let mut as_ref_impls = Vec::new();
+    let mut names = GeneratedNames::new("method");
+    let mut types = GeneratedNames::new("type");
+
+    let default_naming = NamingAttribute::default();
+    let naming = enum_attributes.naming.as_ref().unwrap_or(&default_naming);
 
     for variant in &data_enum.variants {
         let variant_ident = &variant.ident;
-        let variant_name_snake_case = variant_ident.to_string().to_case(Case::Snake);
+        let variant_name = naming.variant_name(variant_ident);
 
-        let attributes = EnumImplAttributes::new(&variant.attrs)?;
+        let mut attributes = EnumImplAttributes::new(&variant.attrs)?;
+        attributes.check_fields(&variant.fields)?;
+        attributes.inherit(&enum_attributes.defaults, variant_ident.span());
+        attributes.apply_payload()?;
+        if let Some(constness) = enum_attributes.constness {
+            attributes.make_const(constness);
+        }
+        attributes.document(enum_attributes.suppresses_doc(), &variant.attrs);
+
+        if let Some(variant_name) = &enum_attributes.variant_name {
+            variant_names.push(attributes.name.clone().unwrap_or_else(|| {
+                let value = variant_ident.unraw().to_string();
+                VariantName {
+                    value: match variant_name.case {
+                        Some(case) => value.to_case(case),
+                        None => value,
+                    },
+                    span: variant_ident.span(),
+                }
+            }));
+        } else if let Some(name) = &attributes.name {
+            return Err(syn::Error::new(
+                name.span,
+                "`name` requires the enum level `variant_name` attribute",
+            ));
+        }
 
         let fields = &variant.fields;
 
         if let Some(params) = &attributes.as_ref_mut {
+            let function_name = params.function_name(naming.method_name("as_ref_mut", variant_ident)?);
+            names.insert(&function_name, params.span);
+            let payload = payload_struct(input, variant, params, "Mut", Borrow::Mutable, &mut types);
             expanded.extend(expand_as_ref_mut(
                 enum_ident,
                 variant_ident,
-                &variant_name_snake_case,
+                &function_name,
                 params,
                 fields,
+                payload.as_ref(),
             ));
+            foreign_impls.extend(payload.map(|payload| payload.definition));
         }
-        if let Some(params) = &attributes.as_ref {
-            expanded.extend(expand_as_ref(
+        if let Some(params) = &attributes.get_or_insert {
+            let function_name = params.function_name(naming.method_name("get_or_insert", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_get_or_insert_with(
                 enum_ident,
                 variant_ident,
-                &variant_name_snake_case,
+                &function_name,
                 params,
                 fields,
             ));
         }
+        if let Some(params) = &attributes.as_ref {
+            match params {
+                ImplOrClassicAttribute::Classic(params) => {
+                    let function_name = params.function_name(naming.method_name("as_ref", variant_ident)?);
+                    names.insert(&function_name, params.span);
+                    let payload = payload_struct(input, variant, params, "Ref", Borrow::Shared, &mut types);
+                    expanded.extend(expand_as_ref(
+                        enum_ident,
+                        variant_ident,
+                        &function_name,
+                        params,
+                        fields,
+                        payload.as_ref(),
+                    ));
+                    foreign_impls.extend(payload.map(|payload| payload.definition));
+                },
+                ImplOrClassicAttribute::Impl(params) => {
+                    reject_bare_param(input, variant, "as_ref")?;
+                    as_ref_impls.push(TraitImpl {
+                        attribute: "as_ref",
+                        key: VariantFields::new(fields, quote! {}).ty.to_string(),
+                        variant_ident,
+                        primary: params.primary,
+                        expanded: expand_try_from_variant(input, variant_ident, fields, &data_enum.variants, true),
+                    });
+                },
+            }
+        }
         if let Some(params) = &attributes.from {
             match params {
-                ImplOrClassicAttribute::Classic(params) => expanded.extend(expand_from_local(
+                ImplOrClassicAttribute::Classic(params) => {
+                    let function_name = params.function_name(naming.method_name("from", variant_ident)?);
+                    names.insert(&function_name, params.span);
+                    expanded.extend(expand_from_local(
+                        enum_ident,
+                        variant_ident,
+                        &function_name,
+                        params,
+                        fields,
+                    ));
+                },
+                ImplOrClassicAttribute::Impl(params) => from_impls.push(TraitImpl {
+                    attribute: "from",
+                    key: VariantFields::new(fields, quote! {}).ty.to_string(),
+                    variant_ident,
+                    primary: params.primary,
+                    expanded: expand_from_foreign(input, variant_ident, fields),
+                }),
+            }
+        }
+        if let Some(params) = &attributes.into {
+            match params {
+                ImplOrClassicAttribute::Classic(params) => {
+                    let function_name = params.function_name(naming.method_name("into", variant_ident)?);
+                    names.insert(&function_name, params.span);
+                    let payload = payload_struct(input, variant, params, "", Borrow::Owned, &mut types);
+                    expanded.extend(expand_into(
+                        enum_ident,
+                        variant_ident,
+                        &function_name,
+                        params,
+                        fields,
+                        payload.as_ref(),
+                    ));
+                    if let (Some(payload), Some(_)) = (&payload, &attributes.payload) {
+                        foreign_impls.extend(expand_from_payload(input, variant_ident, fields, payload));
+                    }
+                    foreign_impls.extend(payload.map(|payload| payload.definition));
+                },
+                ImplOrClassicAttribute::Impl(params) => {
+                    if attributes.try_from.is_some() {
+                        return Err(syn::Error::new(
+                            params.span,
+                            "`impl into` and `impl try_from` both implement `TryFrom` for the associated data, use \
+                             only one of them",
+                        ));
+                    }
+                    reject_bare_param(input, variant, "into")?;
+
+                    let into_impl = TraitImpl {
+                        attribute: "into",
+                        key: VariantFields::new(fields, quote! {}).ty.to_string(),
+                        variant_ident,
+                        primary: params.primary,
+                        expanded: expand_try_from_variant(input, variant_ident, fields, &data_enum.variants, false),
+                    };
+                    reject_same_target(&into_impl, &try_from_impls)?;
+                    into_impls.push(into_impl);
+                },
+            }
+        }
+        if let Some(params) = &attributes.try_into {
+            let function_name = params.function_name(naming.method_name("try_into", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_try_into(
+                enum_ident,
+                variant_ident,
+                &function_name,
+                params,
+                fields,
+            ));
+        }
+        if let Some(params) = &attributes.try_from {
+            reject_bare_param(input, variant, "try_from")?;
+            let try_from_impl = TraitImpl {
+                attribute: "try_from",
+                key: VariantFields::new(fields, quote! {}).ty.to_string(),
+                variant_ident,
+                primary: params.primary,
+                expanded: expand_try_from_foreign(input, variant_ident, fields),
+            };
+            reject_same_target(&try_from_impl, &into_impls)?;
+            try_from_impls.push(try_from_impl);
+        }
+        if let Some(params) = &attributes.unwrap {
+            let function_name = params.function_name(naming.method_name("unwrap", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_unwrap(
+                enum_ident,
+                variant_ident,
+                &function_name,
+                params,
+                fields,
+                &data_enum.variants,
+            ));
+        }
+        if let Some(params) = &attributes.unwrap_ref {
+            let function_name = params.function_name(naming.method_name("unwrap_ref", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_unwrap_ref(
+                enum_ident,
+                variant_ident,
+                &function_name,
+                params,
+                fields,
+                &data_enum.variants,
+            ));
+        }
+        if let Some(params) = &attributes.unwrap_mut {
+            let function_name = params.function_name(naming.method_name("unwrap_mut", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_unwrap_mut(
+                enum_ident,
+                variant_ident,
+                &function_name,
+                params,
+                fields,
+                &data_enum.variants,
+            ));
+        }
+        if let Some(params) = &attributes.expect {
+            let function_name = params.function_name(naming.method_name("expect", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_expect(
+                enum_ident,
+                variant_ident,
+                &function_name,
+                params,
+                fields,
+                &data_enum.variants,
+            ));
+        }
+        if let Some(params) = &attributes.fields {
+            for (i, field) in fields.iter().enumerate() {
+                let function_name = format_ident!("{}_{}", variant_name, field_name(i, field));
+                names.insert(&function_name, params.span);
+                expanded.extend(expand_field_ref(
                     enum_ident,
                     variant_ident,
-                    &variant_name_snake_case,
+                    &function_name,
                     params,
-                    fields,
-                )),
-                ImplOrClassicAttribute::Impl => foreign_impls.extend(expand_from_foreign(input, variant_ident, fields)),
+                    &field_member(i, field),
+                    &field.ty,
+                ));
             }
         }
-        if let Some(params) = &attributes.into {
-            expanded.extend(expand_into(
+        if let Some(params) = &attributes.fields_mut {
+            for (i, field) in fields.iter().enumerate() {
+                let function_name = format_ident!("{}_{}_mut", variant_name, field_name(i, field));
+                names.insert(&function_name, params.span);
+                expanded.extend(expand_field_mut(
+                    enum_ident,
+                    variant_ident,
+                    &function_name,
+                    params,
+                    &field_member(i, field),
+                    &field.ty,
+                ));
+            }
+        }
+        if let Some(params) = &attributes.map {
+            let function_name = params.function_name(naming.method_name("map", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_map(enum_ident, variant_ident, &function_name, params, fields));
+        }
+        if let Some(params) = &attributes.and_then {
+            let function_name = params.function_name(naming.method_name("and_then", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_and_then(
                 enum_ident,
                 variant_ident,
-                &variant_name_snake_case,
+                &function_name,
                 params,
                 fields,
             ));
         }
-        if let Some(params) = &attributes.is {
-            expanded.extend(expand_is(
+        if let Some(params) = &attributes.map_or {
+            let function_name = params.function_name(naming.method_name("map_or", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_map_or(
+                enum_ident,
+                variant_ident,
+                &function_name,
+                params,
+                fields,
+                generics,
+            ));
+        }
+        if let Some(params) = &attributes.set {
+            let function_name = params.function_name(naming.method_name("set", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_set_variant(
+                enum_ident,
+                variant_ident,
+                &function_name,
+                params,
+                fields,
+            ));
+        }
+        if let Some(params) = &attributes.replace {
+            let function_name = params.function_name(naming.method_name("replace", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_replace_with(
                 enum_ident,
                 variant_ident,
-                &variant_name_snake_case,
+                &function_name,
                 params,
                 fields,
             ));
         }
+        if let Some(params) = &attributes.take {
+            let function_name = params.function_name(naming.method_name("take", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_take(enum_ident, variant_ident, &function_name, params, fields));
+        }
+        if let Some(params) = &attributes.is {
+            let function_name = params.function_name(naming.method_name("is", variant_ident)?);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_is(enum_ident, variant_ident, &function_name, params, fields));
+        }
+
+        variant_attributes.push(attributes);
+    }
+
+    foreign_impls.extend(resolve_trait_impls(&from_impls)?);
+    foreign_impls.extend(resolve_trait_impls(&into_impls)?);
+    foreign_impls.extend(resolve_trait_impls(&try_from_impls)?);
+    foreign_impls.extend(resolve_trait_impls(&as_ref_impls)?);
+    if let Some(first) = into_impls.iter().chain(&as_ref_impls).next() {
+        types.insert(&try_from_error_ident(enum_ident), first.variant_ident.span());
+        foreign_impls.extend(expand_try_from_error(input));
     }
 
+    let common_fields = match &enum_attributes.common {
+        Some(params) => {
+            let common_fields = common_fields(data_enum, params)?;
+
+            for (field_ident, ty) in &common_fields {
+                names.insert(field_ident, params.span);
+                expanded.extend(expand_common_field_ref(
+                    enum_ident,
+                    data_enum,
+                    field_ident,
+                    field_ident,
+                    ty,
+                    params,
+                ));
+
+                let function_name = format_ident!("{}_mut", field_ident);
+                names.insert(&function_name, params.span);
+                expanded.extend(expand_common_field_mut(
+                    enum_ident,
+                    data_enum,
+                    field_ident,
+                    &function_name,
+                    ty,
+                    params,
+                ));
+
+                let function_name = format_ident!("into_{}", field_ident);
+                names.insert(&function_name, params.span);
+                expanded.extend(expand_common_field_into(
+                    enum_ident,
+                    data_enum,
+                    field_ident,
+                    &function_name,
+                    ty,
+                    params,
+                ));
+            }
+
+            common_fields
+        },
+        None => Vec::new(),
+    };
+
+    expanded.extend(expand_common_fields_ref(
+        enum_ident,
+        data_enum,
+        &variant_attributes,
+        &common_fields,
+        &mut names,
+    ));
+
+    for params in &enum_attributes.groups {
+        let variants = group_variants(data_enum, params)?;
+
+        names.insert(&params.name, params.span);
+        expanded.extend(expand_is_any(enum_ident, params, &variants));
+
+        if let Some(function_name) = &params.view {
+            let name = params.name.unraw().to_string();
+            let view_ident = format_ident!(
+                "{}{}",
+                enum_ident,
+                name.strip_prefix("is_").unwrap_or(&name).to_case(Case::Pascal)
+            );
+
+            // The custom documentation of the group is the one of its predicate.
+            let view_params = ViewAttribute {
+                vis: params.vis.clone(),
+                constness: params.constness,
+                rename: None,
+                span: params.span,
+                name: None,
+                derives: params.derives.clone(),
+                attrs: params.attrs.clone(),
+                doc: params.doc.default_only(),
+            };
+
+            names.insert(function_name, params.span);
+            types.insert(&view_ident, params.span);
+            foreign_impls.extend(expand_view(
+                input,
+                data_enum,
+                &view_ident,
+                function_name,
+                &view_params,
+                &variants,
+                false,
+            ));
+        }
+    }
+
+    let variants = data_enum.variants.iter().collect::<Vec<_>>();
+
+    if let Some(params) = &enum_attributes.view {
+        let function_name = params.rename.clone().unwrap_or(format_ident!("as_view"));
+        let view_ident = params.name.clone().unwrap_or(format_ident!("{}Ref", enum_ident));
+
+        names.insert(&function_name, params.span);
+        types.insert(&view_ident, params.span);
+        foreign_impls.extend(expand_view(
+            input,
+            data_enum,
+            &view_ident,
+            &function_name,
+            params,
+            &variants,
+            false,
+        ));
+    }
+    if let Some(params) = &enum_attributes.view_mut {
+        let function_name = params.rename.clone().unwrap_or(format_ident!("as_view_mut"));
+        let view_ident = params.name.clone().unwrap_or(format_ident!("{}Mut", enum_ident));
+
+        names.insert(&function_name, params.span);
+        types.insert(&view_ident, params.span);
+        foreign_impls.extend(expand_view(
+            input,
+            data_enum,
+            &view_ident,
+            &function_name,
+            params,
+            &variants,
+            true,
+        ));
+    }
+
+    if let Some(params) = &enum_attributes.kind {
+        let function_name = params.rename.clone().unwrap_or(format_ident!("kind"));
+        let kind_ident = params.name.clone().unwrap_or(format_ident!("{}Kind", enum_ident));
+
+        names.insert(&function_name, params.span);
+        types.insert(&kind_ident, params.span);
+        foreign_impls.extend(expand_kind(input, data_enum, &kind_ident, &function_name, params));
+    }
+    if let Some(params) = &enum_attributes.variant_name {
+        let function_name = params.rename.clone().unwrap_or(format_ident!("variant_name"));
+        names.insert(&function_name, params.span);
+        if params.from_str {
+            types.insert(&parse_error_ident(enum_ident), params.span);
+        }
+        foreign_impls.extend(expand_variant_name(
+            input,
+            data_enum,
+            &function_name,
+            params,
+            &variant_names,
+        )?);
+    }
+
+    names.check()?;
+    types.check()?;
+
     expanded = quote! {
         impl #impl_generics #enum_ident #ty_generics #where_clause {
             #expanded
@@ -105,3 +590,418 @@ fn try_expand(input: &DeriveInput) -> syn::Result<TokenStream> {
 
     Ok(expanded)
 }
+
+fn try_expand_struct(input: &DeriveInput, data_struct: &DataStruct) -> syn::Result<TokenStream> {
+    let struct_ident = &input.ident;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let struct_attributes = StructAttributes::new(&input.attrs)?;
+
+    let mut expanded = TokenStream::default();
+    let mut names = GeneratedNames::new("method");
+
+    if let Some(params) = &struct_attributes.new {
+        let function_name = params.function_name(format_ident!("new"));
+        names.insert(&function_name, params.span);
+        expanded.extend(expand_new(struct_ident, &function_name, params, &data_struct.fields));
+    }
+    if let Some(params) = &struct_attributes.into_parts {
+        let function_name = params.function_name(format_ident!("into_parts"));
+        names.insert(&function_name, params.span);
+        expanded.extend(expand_into_parts(
+            struct_ident,
+            &function_name,
+            params,
+            &data_struct.fields,
+        ));
+    }
+
+    for (i, field) in data_struct.fields.iter().enumerate() {
+        let field_ident = match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field_{}", i),
+        };
+        let member = field_member(i, field);
+
+        let mut attributes = FieldImplAttributes::new(&field.attrs)?;
+        attributes.inherit(
+            &struct_attributes.defaults,
+            field.ident.as_ref().map_or_else(|| field.ty.span(), Ident::span),
+        );
+        if let Some(constness) = struct_attributes.constness {
+            attributes.make_const(constness);
+        }
+
+        let ty = &field.ty;
+
+        if let Some(params) = &attributes.get {
+            let function_name = params.function_name(field_ident.clone());
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_get(&function_name, &member, params, ty));
+        }
+        if let Some(params) = &attributes.get_mut {
+            let function_name = params.function_name(format_ident!("{}_mut", field_ident));
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_get_mut(&function_name, &member, params, ty));
+        }
+        if let Some(params) = &attributes.set {
+            let function_name = params.function_name(format_ident!("set_{}", field_ident));
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_set(&function_name, &member, params, ty));
+        }
+        if let Some(params) = &attributes.with {
+            let function_name = params.function_name(format_ident!("with_{}", field_ident));
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_with(&function_name, &member, params, ty));
+        }
+    }
+
+    names.check()?;
+
+    expanded = quote! {
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            #expanded
+        }
+    };
+
+    Ok(expanded)
+}
+
+/// Name of a field in the names of the generated methods: its identifier, or its index for tuple variants.
+fn field_name(index: usize, field: &Field) -> String {
+    match &field.ident {
+        Some(ident) => ident.unraw().to_string(),
+        None => index.to_string(),
+    }
+}
+
+/// Struct returned by the method generated by `params` for `variant` instead of a tuple, if the `named` option is set
+/// and the variant has several named fields, or a single one with `payload`. Its name is `{Enum}{Variant}{suffix}`
+/// unless given.
+fn payload_struct(
+    input: &DeriveInput,
+    variant: &Variant,
+    params: &ClassicAttribute,
+    suffix: &str,
+    borrow: Borrow,
+    types: &mut GeneratedNames,
+) -> Option<PayloadStruct> {
+    let named = params.named.as_ref()?;
+
+    let min_fields = if named.single_field { 1 } else { 2 };
+    if !matches!(variant.fields, Fields::Named(_)) || variant.fields.len() < min_fields {
+        return None;
+    }
+
+    let struct_ident = named
+        .name
+        .clone()
+        .unwrap_or_else(|| format_ident!("{}{}{}", input.ident, variant.ident, suffix));
+    types.insert(&struct_ident, named.span);
+
+    Some(expand_payload_struct(
+        input,
+        variant,
+        &struct_ident,
+        &params.vis,
+        named.derives.as_deref(),
+        borrow,
+    ))
+}
+
+fn field_member(index: usize, field: &Field) -> Member {
+    match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(index.into()),
+    }
+}
+
+/// Trait implementation generated for a variant with the `impl from`, `impl into`, `impl as_ref` or `impl try_from`
+/// attribute.
+struct TraitImpl<'a> {
+    attribute: &'static str,
+    /// Normalized associated data type, i.e. the type the implementation converts from or into.
+    key: String,
+    variant_ident: &'a Ident,
+    primary: bool,
+    expanded: TokenStream,
+}
+
+/// Keeps a single implementation of the same attribute per associated data type, so that variants with the same
+/// associated data do not produce conflicting implementations. Types are compared syntactically: a type alias or a
+/// different path to the same type is not detected.
+fn resolve_trait_impls(trait_impls: &[TraitImpl]) -> syn::Result<TokenStream> {
+    let mut expanded = TokenStream::default();
+
+    for trait_impl in trait_impls {
+        let mut conflicting = trait_impls.iter().filter(|other| other.key == trait_impl.key);
+        let mut primaries = conflicting.clone().filter(|other| other.primary);
+
+        // The first primary variant wins, or the first variant if none of them is primary.
+        let winner = primaries.next().or_else(|| conflicting.next()).unwrap();
+
+        if std::ptr::eq(winner, trait_impl) {
+            expanded.extend(trait_impl.expanded.clone());
+        } else if trait_impl.primary || !winner.primary {
+            return Err(syn::Error::new_spanned(
+                trait_impl.variant_ident,
+                format!(
+                    "conflicting `impl {}` with variant `{}`, which has the same associated data type `{}`; mark \
+                     exactly one of them with `impl {}(primary)`",
+                    trait_impl.attribute, winner.variant_ident, trait_impl.key, trait_impl.attribute,
+                ),
+            ));
+        }
+    }
+
+    Ok(expanded)
+}
+
+/// Rejects `trait_impl` if one of `others`, generated by another attribute for a previous variant, implements the same
+/// trait for the same associated data type. `impl into` and `impl try_from` both implement `TryFrom<Enum>`, and
+/// `primary` cannot choose between them since their error types differ.
+fn reject_same_target(trait_impl: &TraitImpl, others: &[TraitImpl]) -> syn::Result<()> {
+    match others.iter().find(|other| other.key == trait_impl.key) {
+        Some(other) => Err(syn::Error::new_spanned(
+            trait_impl.variant_ident,
+            format!(
+                "conflicting `impl {}` with the `impl {}` of variant `{}`, which both implement `TryFrom` for the \
+                 associated data type `{}`",
+                trait_impl.attribute, other.attribute, other.variant_ident, trait_impl.key,
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Rejects the `impl {attribute}` of a variant whose associated data is a type parameter of the enum, possibly behind a
+/// reference. The orphan rules forbid implementing `TryFrom` for it, which the compiler would report on the derive.
+fn reject_bare_param(input: &DeriveInput, variant: &Variant, attribute: &str) -> syn::Result<()> {
+    fn param_ident<'a>(input: &'a DeriveInput, ty: &Type) -> Option<&'a Ident> {
+        match ty {
+            Type::Reference(reference) => param_ident(input, &reference.elem),
+            Type::Group(group) => param_ident(input, &group.elem),
+            Type::Paren(paren) => param_ident(input, &paren.elem),
+            Type::Path(path) if path.qself.is_none() => {
+                let ident = path.path.get_ident()?;
+                input
+                    .generics
+                    .type_params()
+                    .map(|param| &param.ident)
+                    .find(|param| *param == ident)
+            },
+            _ => None,
+        }
+    }
+
+    let mut fields = variant.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Ok(());
+    };
+
+    // The method generated by the attribute without `impl` converts the same way.
+    let method = if attribute == "try_from" { "try_into" } else { attribute };
+
+    match param_ident(input, &field.ty) {
+        Some(param) => Err(syn::Error::new_spanned(
+            &variant.ident,
+            format!(
+                "`impl {}` cannot implement `TryFrom` for the type parameter `{}`, which is not local to this crate; \
+                 use `{}` instead",
+                attribute, param, method,
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Resolves the variants of the `group` attribute, in the listed order.
+fn group_variants<'a>(data_enum: &'a DataEnum, params: &GroupAttribute) -> syn::Result<Vec<&'a Variant>> {
+    let mut res: Vec<&Variant> = Vec::new();
+
+    for variant_ident in &params.variants {
+        let Some(variant) = data_enum
+            .variants
+            .iter()
+            .find(|variant| variant.ident == *variant_ident)
+        else {
+            return Err(syn::Error::new_spanned(
+                variant_ident,
+                format!("unknown variant `{}`", variant_ident),
+            ));
+        };
+
+        if res.iter().any(|other| other.ident == *variant_ident) {
+            return Err(syn::Error::new_spanned(variant_ident, "duplicated variant"));
+        }
+
+        res.push(variant);
+    }
+
+    Ok(res)
+}
+
+/// Returns the named fields present with the same type in every variant, in the order of the first variant.
+fn shared_fields(data_enum: &DataEnum) -> Vec<(&Ident, &Type)> {
+    let Some(first) = data_enum.variants.first() else {
+        return Vec::new();
+    };
+
+    first
+        .fields
+        .iter()
+        .filter_map(|field| {
+            let field_ident = field.ident.as_ref()?;
+            let ty = type_key(&field.ty);
+
+            let shared = data_enum.variants.iter().all(|variant| {
+                variant
+                    .fields
+                    .iter()
+                    .any(|other| other.ident.as_ref() == Some(field_ident) && type_key(&other.ty) == ty)
+            });
+
+            shared.then_some((field_ident, &field.ty))
+        })
+        .collect()
+}
+
+/// Resolves the fields of the `common` attribute, checking that declared fields are present with the same type in
+/// every variant.
+fn common_fields<'a>(data_enum: &'a DataEnum, params: &'a CommonAttribute) -> syn::Result<Vec<(&'a Ident, &'a Type)>> {
+    let Some(declared) = &params.fields else {
+        return Ok(shared_fields(data_enum));
+    };
+
+    let mut res = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |error: syn::Error| match &mut errors {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
+    };
+
+    for field_ident in declared {
+        let mut expected: Option<(&Ident, &Type)> = None;
+
+        for variant in &data_enum.variants {
+            let Some(field) = variant
+                .fields
+                .iter()
+                .find(|field| field.ident.as_ref() == Some(field_ident))
+            else {
+                push_error(syn::Error::new_spanned(
+                    &variant.ident,
+                    format!("variant `{}` has no field `{}`", variant.ident, field_ident),
+                ));
+                continue;
+            };
+
+            match expected {
+                Some((first_variant, ty)) if type_key(ty) != type_key(&field.ty) => {
+                    push_error(syn::Error::new_spanned(
+                        &field.ty,
+                        format!(
+                            "field `{}` has type `{}` in variant `{}`, but type `{}` here",
+                            field_ident,
+                            ty.to_token_stream(),
+                            first_variant,
+                            field.ty.to_token_stream(),
+                        ),
+                    ));
+                },
+                Some(_) => {},
+                None => expected = Some((&variant.ident, &field.ty)),
+            }
+        }
+
+        if let Some((_, ty)) = expected {
+            res.push((field_ident, ty));
+        }
+    }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(res),
+    }
+}
+
+/// Generates the accessors of the named fields present with the same type in every variant, if every variant has
+/// the `fields` (resp. `fields_mut`) attribute. Fields already handled by the `common` attribute are skipped.
+fn expand_common_fields_ref(
+    enum_ident: &Ident,
+    data_enum: &DataEnum,
+    variant_attributes: &[EnumImplAttributes],
+    common_fields: &[(&Ident, &Type)],
+    names: &mut GeneratedNames,
+) -> TokenStream {
+    let mut expanded = TokenStream::default();
+
+    let immutable = variant_attributes
+        .iter()
+        .map(|attributes| attributes.fields.as_ref())
+        .collect::<Option<Vec<_>>>();
+    let mutable = variant_attributes
+        .iter()
+        .map(|attributes| attributes.fields_mut.as_ref())
+        .collect::<Option<Vec<_>>>();
+
+    let immutable = immutable.as_deref().and_then(common_params);
+    let mutable = mutable.as_deref().and_then(common_params);
+
+    for (field_ident, ty) in shared_fields(data_enum) {
+        if common_fields.iter().any(|(common, _)| *common == field_ident) {
+            continue;
+        }
+
+        if let Some(params) = &immutable {
+            names.insert(field_ident, params.span);
+            expanded.extend(expand_common_field_ref(
+                enum_ident,
+                data_enum,
+                field_ident,
+                field_ident,
+                ty,
+                params,
+            ));
+        }
+        if let Some(params) = &mutable {
+            let function_name = format_ident!("{}_mut", field_ident);
+            names.insert(&function_name, params.span);
+            expanded.extend(expand_common_field_mut(
+                enum_ident,
+                data_enum,
+                field_ident,
+                &function_name,
+                ty,
+                params,
+            ));
+        }
+    }
+
+    expanded
+}
+
+/// Parameters of the accessors generated from the `fields` (resp. `fields_mut`) attribute of every variant: the
+/// visibility shared by all `params`, or private if they differ, and the default documentation unless suppressed.
+fn common_params(params: &[&ClassicAttribute]) -> Option<CommonAttribute> {
+    let first = params.first()?;
+    let first_key = first.vis.to_token_stream().to_string();
+
+    let vis = if params
+        .iter()
+        .all(|params| params.vis.to_token_stream().to_string() == first_key)
+    {
+        first.vis.clone()
+    } else {
+        Visibility::Inherited
+    };
+
+    Some(CommonAttribute {
+        vis,
+        span: first.span,
+        attrs: ForwardedAttributes::default(),
+        doc: first.doc.default_only(),
+        fields: None,
+    })
+}
@@ -0,0 +1,153 @@
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{Fields, GenericParam, Generics, Lifetime, LifetimeParam, Variant};
+
+/// Tokens used to destructure the fields of a variant and to hand them back.
+pub struct VariantFields {
+    /// Type of the associated data: the field type if there is a single field, a tuple of the field types otherwise.
+    pub ty: TokenStream,
+    /// Pattern binding every field of the variant, e.g. `{ x, y }` or `(arg0, arg1)`.
+    pub destruct: TokenStream,
+    /// Expression building the associated data from the bindings of `destruct`.
+    pub ret: TokenStream,
+    /// Function parameters taking every field, e.g. `x: i64, y: i64`.
+    pub input: TokenStream,
+}
+
+impl VariantFields {
+    /// Builds the tokens for `fields`, prefixing every field type with `reference` (e.g. `&` or `&mut`).
+    pub fn new(fields: &Fields, reference: TokenStream) -> Self {
+        let (idents, tys): (Vec<_>, Vec<_>) = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| (binding_ident(i, field), &field.ty))
+            .unzip();
+
+        let destruct = match fields {
+            Fields::Named(_) => quote! { { #(#idents),* } },
+            Fields::Unnamed(_) => quote! { ( #(#idents),* ) },
+            Fields::Unit => quote! {},
+        };
+
+        let (ty, ret) = if idents.len() == 1 {
+            let ty = tys[0];
+            let ident = &idents[0];
+            (quote! { #reference #ty }, quote! { #ident })
+        } else {
+            (quote! { ( #(#reference #tys),* ) }, quote! { ( #(#idents),* ) })
+        };
+
+        let input = quote! { #(#idents: #tys),* };
+
+        Self {
+            ty,
+            destruct,
+            ret,
+            input,
+        }
+    }
+}
+
+/// Identifier a field is bound to when destructuring a variant.
+pub fn binding_ident(index: usize, field: &syn::Field) -> Ident {
+    field
+        .ident
+        .clone()
+        .unwrap_or_else(|| Ident::new(&format!("arg{index}"), Span::call_site()))
+}
+
+/// Pattern matching a variant with `fields` without binding any of them.
+pub fn wildcard_pattern(fields: &Fields) -> TokenStream {
+    match fields {
+        Fields::Named(_) => quote! { { .. } },
+        Fields::Unnamed(_) => quote! { ( .. ) },
+        Fields::Unit => quote! {},
+    }
+}
+
+/// Doc attributes of `field`, copied onto the fields generated from it.
+pub fn field_docs(field: &syn::Field) -> impl Iterator<Item = &syn::Attribute> {
+    field.attrs.iter().filter(|attr| attr.path().is_ident("doc"))
+}
+
+/// Normalized representation of `ty`, used to compare types across variants.
+pub fn type_key(ty: &syn::Type) -> String {
+    ty.to_token_stream().to_string()
+}
+
+/// Lifetime of the references to the fields of the enum, which must not clash with the lifetimes of the enum.
+pub fn fresh_lifetime(generics: &Generics) -> Lifetime {
+    let taken = |name: &str| generics.lifetimes().any(|param| param.lifetime.ident == name);
+
+    let name = ["a", "b", "c"]
+        .into_iter()
+        .find(|name| !taken(name))
+        .map_or_else(|| "view".to_owned(), str::to_owned);
+
+    Lifetime::new(&format!("'{}", name), Span::call_site())
+}
+
+/// Generics of the enum used by the fields of `variants`, preceded by `lifetime` if any. Generics of other variants
+/// are dropped, since unused generics are rejected, along with the predicates mentioning them.
+pub fn used_generics(generics: &Generics, variants: &[&Variant], lifetime: Option<&Lifetime>) -> Generics {
+    let fields = variants
+        .iter()
+        .flat_map(|variant| &variant.fields)
+        .map(|field| field.ty.to_token_stream())
+        .collect::<TokenStream>();
+
+    let (kept, dropped): (Vec<_>, Vec<_>) = generics
+        .params
+        .iter()
+        .partition(|param| mentions(fields.clone(), param_ident(param)));
+
+    let mut res = Generics {
+        lt_token: None,
+        params: Default::default(),
+        gt_token: None,
+        where_clause: generics.where_clause.clone(),
+    };
+
+    if let Some(lifetime) = lifetime {
+        res.params
+            .push(GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    }
+    res.params.extend(kept.into_iter().cloned());
+
+    if !res.params.is_empty() {
+        res.lt_token = Some(Default::default());
+        res.gt_token = Some(Default::default());
+    }
+
+    if let Some(where_clause) = &mut res.where_clause {
+        where_clause.predicates = where_clause
+            .predicates
+            .iter()
+            .filter(|predicate| {
+                !dropped
+                    .iter()
+                    .any(|param| mentions(predicate.to_token_stream(), param_ident(param)))
+            })
+            .cloned()
+            .collect();
+    }
+
+    res
+}
+
+fn param_ident(param: &GenericParam) -> &Ident {
+    match param {
+        GenericParam::Lifetime(param) => &param.lifetime.ident,
+        GenericParam::Type(param) => &param.ident,
+        GenericParam::Const(param) => &param.ident,
+    }
+}
+
+/// Whether `tokens` contain `ident`, which is enough to tell whether a generic parameter or a type name is used.
+pub fn mentions(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        TokenTree::Ident(other) => other == *ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
@@ -6,6 +6,8 @@ use syn::{parse_macro_input, DeriveInput};
 mod attr;
 mod components;
 mod expand;
+mod fields;
+mod names;
 
 #[proc_macro_derive(EnumImpl, attributes(enum_impl))]
 pub fn derive_enum_impl(input: TokenStream) -> TokenStream {
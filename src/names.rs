@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Ident, Span};
+use syn::ext::IdentExt;
+
+/// Names of the generated methods or types, used to report items generated twice instead of letting the compiler
+/// report an opaque duplicate definition.
+pub struct GeneratedNames {
+    /// Kind of the generated items, e.g. `"method"`.
+    kind: &'static str,
+    names: HashMap<String, Span>,
+    errors: Option<syn::Error>,
+}
+
+impl GeneratedNames {
+    pub fn new(kind: &'static str) -> Self {
+        Self {
+            kind,
+            names: HashMap::new(),
+            errors: None,
+        }
+    }
+
+    /// Records `name`, generated by the attribute at `span`.
+    pub fn insert(&mut self, name: &Ident, span: Span) {
+        let key = name.unraw().to_string();
+
+        let Some(&previous) = self.names.get(&key) else {
+            self.names.insert(key, span);
+            return;
+        };
+
+        let mut error = syn::Error::new(span, format!("duplicate generated {} `{}`", self.kind, key));
+        error.combine(syn::Error::new(
+            previous,
+            format!("{} `{}` first generated here", self.kind, key),
+        ));
+
+        match &mut self.errors {
+            Some(errors) => errors.combine(error),
+            None => self.errors = Some(error),
+        }
+    }
+
+    /// Returns the errors of every duplicate name.
+    pub fn check(self) -> syn::Result<()> {
+        match self.errors {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+}
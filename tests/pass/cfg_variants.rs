@@ -0,0 +1,29 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, PartialEq, EnumImpl)]
+#[enum_impl(pub kind, pub variant_name)]
+enum Value {
+    #[enum_impl(pub is, pub into, impl from)]
+    Int(i64),
+    #[cfg(any())]
+    #[enum_impl(pub is, pub into, impl from)]
+    Missing(NotDefined),
+    #[cfg_attr(all(), enum_impl(pub is, pub into, impl from))]
+    Text(String),
+    #[cfg_attr(any(), enum_impl(pub is))]
+    Empty,
+}
+
+fn main() {
+    assert_eq!(Value::VARIANT_NAMES, ["Int", "Text", "Empty"]);
+    assert_eq!(ValueKind::ALL, [ValueKind::Int, ValueKind::Text, ValueKind::Empty]);
+
+    let text = Value::from(String::from("a"));
+    assert!(text.is_text());
+    assert_eq!(text.kind(), ValueKind::Text);
+    assert_eq!(text.variant_name(), "Text");
+    assert_eq!(text.into_text(), Some(String::from("a")));
+
+    assert!(Value::from(1).is_int());
+    assert_eq!(Value::Empty.variant_name(), "Empty");
+}
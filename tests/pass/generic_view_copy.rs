@@ -0,0 +1,18 @@
+use enum_impl::EnumImpl;
+
+pub struct NoClone;
+
+#[derive(EnumImpl)]
+#[enum_impl(pub view)]
+enum Value<T> {
+    X(T),
+    Y(NoClone),
+}
+
+fn assert_copy<T: Copy>(_: T) {}
+
+fn main() {
+    let value = Value::<u8>::Y(NoClone);
+    assert_copy(value.as_view());
+    assert!(matches!(Value::X(1).as_view(), ValueRef::X(1)));
+}
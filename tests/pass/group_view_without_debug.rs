@@ -0,0 +1,21 @@
+use enum_impl::EnumImpl;
+
+pub struct NoDebug;
+
+#[derive(EnumImpl)]
+#[enum_impl(pub group(name = "is_data", variants(X, Z), view))]
+enum Value {
+    X(NoDebug),
+    Y,
+    Z { inner: NoDebug },
+}
+
+fn main() {
+    let value = Value::X(NoDebug);
+    let view = value.as_data().unwrap();
+    let copy = view;
+    assert!(matches!(copy, ValueData::X(_)));
+    assert!(matches!(view, ValueData::X(_)));
+    assert!(Value::Y.as_data().is_none());
+    assert!(Value::Z { inner: NoDebug }.is_data());
+}
@@ -0,0 +1,14 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, PartialEq, EnumImpl)]
+enum Value<T> {
+    #[enum_impl(impl try_from)]
+    List(Vec<T>),
+    #[enum_impl(impl into)]
+    Pair(T, u8),
+}
+
+fn main() {
+    assert_eq!(Vec::try_from(Value::List(vec![1])), Ok(vec![1]));
+    assert_eq!(<(i32, u8)>::try_from(Value::Pair(1, 2)).ok(), Some((1, 2)));
+}
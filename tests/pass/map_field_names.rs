@@ -0,0 +1,16 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, PartialEq, EnumImpl)]
+#[enum_impl(pub map, pub and_then, pub map_or)]
+enum Value {
+    X { f: u8, default: u8 },
+    Y(u8),
+}
+
+fn main() {
+    let value = Value::X { f: 1, default: 2 }.map_x(|(f, default)| (f + 1, default + 1));
+    assert_eq!(value, Value::X { f: 2, default: 3 });
+    assert_eq!(value.map_x_or(0, |(f, default)| f + default), 5);
+    let value = Value::X { f: 1, default: 2 }.and_then_x(|(f, _)| Value::Y(f));
+    assert_eq!(value, Value::Y(1));
+}
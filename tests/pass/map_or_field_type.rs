@@ -0,0 +1,16 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, PartialEq)]
+pub struct U(u8);
+
+#[derive(EnumImpl)]
+#[enum_impl(pub map_or)]
+enum Value<T> {
+    A(U),
+    B(Vec<T>),
+}
+
+fn main() {
+    assert_eq!(Value::<()>::A(U(1)).map_a_or(U(0), |U(x)| U(x + 1)), U(2));
+    assert_eq!(Value::<()>::B(Vec::new()).map_a_or(0, |U(x)| x), 0);
+}
@@ -0,0 +1,26 @@
+//! Named structs of an enum with documented fields.
+#![deny(missing_docs)]
+
+use enum_impl::EnumImpl;
+
+/// A shape.
+#[derive(EnumImpl)]
+#[enum_impl(pub into(named), pub as_ref(named), pub as_ref_mut(named))]
+pub enum Shape {
+    /// A rectangle.
+    Rectangle {
+        /// Width of the rectangle.
+        width: f64,
+        /// Height of the rectangle.
+        height: f64,
+    },
+    /// A square.
+    Square(f64),
+}
+
+fn main() {
+    let mut shape = Shape::Rectangle { width: 1.0, height: 2.0 };
+    assert_eq!(shape.as_rectangle().map(|fields| *fields.width), Some(1.0));
+    assert!(shape.as_rectangle_mut().is_some());
+    assert_eq!(shape.into_rectangle().map(|fields| fields.height), Some(2.0));
+}
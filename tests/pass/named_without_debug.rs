@@ -0,0 +1,22 @@
+use enum_impl::EnumImpl;
+
+pub struct NoDebug;
+
+#[derive(EnumImpl)]
+#[enum_impl(pub into(named), pub as_ref(named), pub as_ref_mut(named))]
+enum Value {
+    X { a: NoDebug, b: u8 },
+    Y(u8),
+}
+
+fn main() {
+    let mut value = Value::X { a: NoDebug, b: 1 };
+    let fields = value.as_x().unwrap();
+    let copy = fields;
+    assert_eq!(*copy.b, 1);
+    assert_eq!(*fields.b, 1);
+    *value.as_x_mut().unwrap().b = 2;
+    let ValueX { a: NoDebug, b } = value.into_x().unwrap();
+    assert_eq!(b, 2);
+    assert_eq!(Value::Y(3).into_y(), Some(3));
+}
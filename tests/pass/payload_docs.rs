@@ -0,0 +1,27 @@
+//! Payload structs of an enum with documented fields.
+#![deny(missing_docs)]
+
+use enum_impl::EnumImpl;
+
+/// A shape.
+#[derive(EnumImpl)]
+#[enum_impl(pub payload)]
+pub enum Shape {
+    /// A cuboid.
+    Cuboid {
+        /// Width of the cuboid.
+        width: f64,
+        /// Height of the cuboid.
+        height: f64,
+        /// Depth of the cuboid.
+        depth: f64,
+    },
+    /// A cube.
+    Cube(f64),
+}
+
+fn main() {
+    let shape = Shape::from(ShapeCuboid { width: 1.0, height: 2.0, depth: 3.0 });
+    assert_eq!(shape.as_cuboid().map(|fields| *fields.depth), Some(3.0));
+    assert_eq!(shape.into_cuboid().map(|fields| fields.width), Some(1.0));
+}
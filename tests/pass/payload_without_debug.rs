@@ -0,0 +1,20 @@
+use enum_impl::EnumImpl;
+
+pub struct NoDebug;
+
+#[derive(EnumImpl)]
+enum Value {
+    #[enum_impl(pub payload)]
+    X { a: NoDebug, b: u8 },
+    Y(u8),
+}
+
+fn main() {
+    let value = Value::from(ValueX { a: NoDebug, b: 1 });
+    let fields = value.as_x().unwrap();
+    let copy = fields;
+    assert_eq!(*copy.b, 1);
+    assert_eq!(*fields.b, 1);
+    let ValueX { a: NoDebug, b } = value.into_x().unwrap();
+    assert_eq!(b, 1);
+}
@@ -0,0 +1,16 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, EnumImpl)]
+#[enum_impl(impl try_from)]
+enum Message {
+    Text(String),
+    #[enum_impl(impl try_from(primary))]
+    Error(String),
+    Code(u16),
+}
+
+fn main() {
+    assert_eq!(String::try_from(Message::Error("a".to_owned())).unwrap(), "a");
+    assert!(String::try_from(Message::Text("a".to_owned())).is_err());
+    assert_eq!(u16::try_from(Message::Code(404)).unwrap(), 404);
+}
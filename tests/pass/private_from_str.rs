@@ -0,0 +1,13 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, PartialEq, EnumImpl)]
+#[enum_impl(variant_name(from_str))]
+pub enum Method {
+    Get,
+    Post,
+}
+
+fn main() {
+    assert_eq!("Post".parse::<Method>(), Ok(Method::Post));
+    assert_eq!("Put".parse::<Method>(), Err(ParseMethodError));
+}
@@ -0,0 +1,17 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+#[enum_impl(pub is, pub as_ref, pub fields)]
+#[allow(non_camel_case_types)]
+enum Token {
+    r#type { name: String },
+    r#loop(u8),
+}
+
+fn main() {
+    let token = Token::r#type { name: "u8".to_owned() };
+    assert!(token.is_type());
+    assert!(!token.is_loop());
+    assert_eq!(token.type_name().unwrap(), "u8");
+    assert_eq!(Token::r#loop(1).as_loop(), Some(&1));
+}
@@ -0,0 +1,15 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, PartialEq, EnumImpl)]
+#[enum_impl(pub variant_name(from_str))]
+#[allow(non_camel_case_types)]
+enum Token {
+    r#type,
+    Ident,
+}
+
+fn main() {
+    assert_eq!(Token::VARIANT_NAMES, ["type", "Ident"]);
+    assert_eq!(Token::r#type.variant_name(), "type");
+    assert_eq!("type".parse::<Token>(), Ok(Token::r#type));
+}
@@ -0,0 +1,17 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, PartialEq, EnumImpl)]
+enum Token {
+    #[enum_impl(as_ref = "type", from = "r#loop", into = get_x, is = r#match)]
+    Word(String),
+    Number(u8),
+}
+
+fn main() {
+    let token = Token::r#loop(String::from("a"));
+    assert_eq!(token, Token::Word(String::from("a")));
+    assert_eq!(token.r#type(), Some(&String::from("a")));
+    assert!(token.r#match());
+    assert!(!Token::Number(1).r#match());
+    assert_eq!(token.get_x(), Some(String::from("a")));
+}
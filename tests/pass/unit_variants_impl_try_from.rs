@@ -0,0 +1,23 @@
+use enum_impl::EnumImpl;
+
+#[derive(Debug, PartialEq, EnumImpl)]
+#[enum_impl(impl try_from, impl from, pub try_into, pub unwrap, pub map, pub take)]
+enum Command {
+    Connect(String),
+    Send(Vec<u8>),
+    Ping,
+    Quit,
+}
+
+impl Default for Command {
+    fn default() -> Self {
+        Self::Ping
+    }
+}
+
+fn main() {
+    assert_eq!(String::try_from(Command::from(String::from("host"))), Ok(String::from("host")));
+    assert_eq!(Vec::<u8>::try_from(Command::Quit), Err(Command::Quit));
+    assert_eq!(Command::Send(vec![1]).unwrap_send(), vec![1]);
+    assert!(Command::Ping.try_into_connect().is_err());
+}
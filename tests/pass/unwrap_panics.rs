@@ -0,0 +1,55 @@
+use std::panic::{self, UnwindSafe};
+use std::sync::{Arc, Mutex};
+
+use enum_impl::EnumImpl;
+
+#[derive(Debug, EnumImpl)]
+#[enum_impl(pub unwrap, pub unwrap_ref, pub unwrap_mut, pub expect)]
+enum Shape {
+    Circle(f64),
+    Square { side: f64 },
+}
+
+/// Calls `f`, expecting it to panic, and returns the panic message and the line it was reported at.
+fn panic_of(f: impl FnOnce() + UnwindSafe) -> (String, u32) {
+    let line = Arc::new(Mutex::new(0));
+    let hook_line = Arc::clone(&line);
+    panic::set_hook(Box::new(move |info| {
+        *hook_line.lock().unwrap() = info.location().unwrap().line();
+    }));
+
+    let payload = panic::catch_unwind(f).unwrap_err();
+    let _ = panic::take_hook();
+
+    let message = payload.downcast::<String>().map(|message| *message).unwrap();
+    let line = *line.lock().unwrap();
+    (message, line)
+}
+
+fn main() {
+    let (message, line) = panic_of(|| {
+        Shape::Square { side: 1.0 }.unwrap_circle();
+    });
+    assert_eq!(message, "expected `Shape::Circle`, found `Shape::Square`");
+    assert_eq!(line, line!() - 3);
+
+    let (message, line) = panic_of(|| {
+        Shape::Circle(1.0).unwrap_square_ref();
+    });
+    assert_eq!(message, "expected `Shape::Square`, found `Shape::Circle`");
+    assert_eq!(line, line!() - 3);
+
+    let (message, line) = panic_of(|| {
+        Shape::Circle(1.0).unwrap_square_mut();
+    });
+    assert_eq!(message, "expected `Shape::Square`, found `Shape::Circle`");
+    assert_eq!(line, line!() - 3);
+
+    let (message, line) = panic_of(|| {
+        Shape::Square { side: 1.0 }.expect_circle("not round");
+    });
+    assert_eq!(message, "not round: expected `Shape::Circle`, found `Shape::Square`");
+    assert_eq!(line, line!() - 3);
+
+    assert_eq!(Shape::Circle(2.0).unwrap_circle(), 2.0);
+}
@@ -0,0 +1,29 @@
+//! Views of an enum with documented named fields.
+#![deny(missing_docs)]
+
+use enum_impl::EnumImpl;
+
+/// A shape.
+#[derive(EnumImpl)]
+#[enum_impl(pub view, pub view_mut, group(name = "is_round", variants(Circle), view))]
+pub enum Shape {
+    /// A circle.
+    Circle {
+        /// Radius of the circle.
+        radius: f64,
+    },
+    /// A rectangle.
+    Rectangle {
+        /// Width of the rectangle.
+        width: f64,
+        /// Height of the rectangle.
+        height: f64,
+    },
+}
+
+fn main() {
+    let mut shape = Shape::Rectangle { width: 1.0, height: 2.0 };
+    assert!(matches!(shape.as_view(), ShapeRef::Rectangle { width, .. } if *width == 1.0));
+    assert!(matches!(shape.as_view_mut(), ShapeMut::Rectangle { .. }));
+    assert!(Shape::Circle { radius: 1.0 }.as_round().is_some());
+}
@@ -0,0 +1,20 @@
+use enum_impl::EnumImpl;
+
+struct NoDebug;
+
+#[derive(EnumImpl)]
+#[enum_impl(pub view, pub view_mut)]
+enum Value {
+    X(NoDebug),
+    Y,
+}
+
+fn main() {
+    let mut value = Value::X(NoDebug);
+    let view = value.as_view();
+    let copy = view;
+    assert!(matches!(copy, ValueRef::X(_)));
+    assert!(matches!(view, ValueRef::X(_)));
+    assert!(matches!(value.as_view_mut(), ValueMut::X(_)));
+    assert!(matches!(Value::Y.as_view(), ValueRef::Y));
+}
@@ -0,0 +1,9 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+enum Shape {
+    #[enum_impl(pub as_ref)]
+    Point,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+#[enum_impl(impl from)]
+enum Message {
+    Text(String),
+    Error(String),
+    Code(u16),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+#[enum_impl(impl try_from)]
+enum Message {
+    Text(String),
+    Error(String),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+enum Message {
+    #[enum_impl(impl try_from)]
+    Text(String),
+    #[enum_impl(impl into)]
+    Error(String),
+}
+
+fn main() {}
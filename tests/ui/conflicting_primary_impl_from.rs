@@ -0,0 +1,11 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+enum Message {
+    #[enum_impl(impl from(primary))]
+    Text(String),
+    #[enum_impl(impl from(primary))]
+    Error(String),
+}
+
+fn main() {}
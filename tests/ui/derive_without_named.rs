@@ -0,0 +1,9 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+enum Value {
+    #[enum_impl(pub into(derive(Debug)))]
+    X { a: u8, b: u8 },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+#[enum_impl(pub is)]
+enum Request {
+    HTTPGet,
+    HttpGet,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+enum Shape {
+    #[enum_impl(pub is = "is_round")]
+    Circle(f64),
+    #[enum_impl(pub is = "is_round")]
+    Ellipse(f64, f64),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+#[enum_impl(pub kind)]
+enum A {
+    #[enum_impl(pub payload)]
+    Kind { x: u8 },
+    B,
+}
+
+fn main() {}
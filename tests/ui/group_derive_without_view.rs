@@ -0,0 +1,10 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+#[enum_impl(pub group(name = "is_data", variants(X), derive(Debug)))]
+enum Value {
+    X(u8),
+    Y,
+}
+
+fn main() {}
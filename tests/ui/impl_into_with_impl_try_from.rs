@@ -0,0 +1,9 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+enum Message {
+    #[enum_impl(impl into, impl try_from)]
+    Text(String),
+}
+
+fn main() {}
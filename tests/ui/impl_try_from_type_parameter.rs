@@ -0,0 +1,10 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+enum Value<T> {
+    #[enum_impl(impl try_from)]
+    Generic(T),
+    Number(u8),
+}
+
+fn main() {}
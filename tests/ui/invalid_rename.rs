@@ -0,0 +1,9 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+enum Shape {
+    #[enum_impl(pub is = "1round")]
+    Circle(f64),
+}
+
+fn main() {}
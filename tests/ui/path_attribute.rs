@@ -0,0 +1,9 @@
+use enum_impl::EnumImpl;
+
+#[derive(EnumImpl)]
+#[enum_impl(core::is)]
+enum Shape {
+    Circle(f64),
+}
+
+fn main() {}